@@ -1,3 +1,4 @@
 #[cfg(feature = "mariadb")]
 pub mod mariadb_innodb;
 pub mod reflection_adapter;
+pub mod server;
@@ -1,26 +1,43 @@
 use crate::adapter::reflection_adapter::ReflectionAdapterError::DatabaseError;
 use crate::adapter::reflection_adapter::{
-    Connected, ReflectionAdapter, ReflectionAdapterError, ReflectionAdapterUninitialized, State,
-    Uninitialized,
+    detect_table_changes, CancellationToken, Connected, ReflectionAdapter, ReflectionAdapterError,
+    ReflectionAdapterUninitialized, ReflectionEvent, ReflectionObserver, State, TableChange,
+    TableStamp, Uninitialized,
 };
+use crate::adapter::server::ServerInfo;
 use crate::metadata::consts::{
-    METADATA_CHARSET, METADATA_COLLATION, METADATA_FLAG_AUTO_INCREMENT,
-    METADATA_FLAG_DEFAULT_CURRENT_TIMESTAMP, METADATA_FLAG_NULLABLE,
-    METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP, METADATA_FLAG_PRIMARY, METADATA_FLAG_UNIQUE,
+    METADATA_FLAG_AUTO_INCREMENT, METADATA_FLAG_DEFAULT_CURRENT_TIMESTAMP,
 };
-use crate::metadata::WithMetadata;
-use crate::reflection::{Column, Constraint, Database, DefaultValue, Index, SqlDatatype, Table};
-use serde_json::Value;
+use crate::metadata::{metadata_entries, WithMetadata};
+use crate::reflection::{
+    Catalog, CheckConstraint, Column, Constraint, Database, DefaultValue, Event, GeneratedKind,
+    ParameterMode, Partition, Routine, RoutineKind, SqlDatatype, Table, Trigger, View,
+};
+use futures::future::{select, Either};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use indexmap::IndexMap;
 use sqlx::mysql::MySqlPoolOptions;
 use sqlx::{MySql, Pool};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Storage engine the adapter is built for
+pub const ENGINE_INNODB: &str = "InnoDB";
 
+//Field 	Type 	Collation 	Null 	Key 	Default 	Extra 	Privileges 	Comment
 type TableComlumnsTuple = (
     String,
     String,
+    Option<String>,
     String,
     Option<String>,
     Option<String>,
     Option<String>,
+    String,
+    String,
 );
 
 type TableIndexTuple = (
@@ -57,13 +74,177 @@ type TableIndexTupleAlt = (
     String,
 );
 
+//COLUMN_NAME 	CHARACTER_SET_NAME 	COLLATION_NAME 	COLUMN_DEFAULT 	GENERATION_EXPRESSION
+type TableColumnDetailsTuple = (
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+//CHARACTER_SET_NAME 	COLLATION_NAME 	TABLE_COMMENT 	ENGINE 	ROW_FORMAT 	AUTO_INCREMENT 	CREATE_OPTIONS
+type TableOptionsTuple = (
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<u64>,
+    Option<String>,
+);
+
+//TABLE_NAME 	CREATE_TIME 	UPDATE_TIME 	CHECKSUM
+type TableStampTuple = (String, Option<String>, Option<String>, Option<String>);
+
+//PARTITION_NAME 	PARTITION_METHOD 	PARTITION_EXPRESSION 	PARTITION_DESCRIPTION 	SUBPARTITION_NAME 	SUBPARTITION_METHOD 	SUBPARTITION_EXPRESSION
+type TablePartitionTuple = (
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+//CONSTRAINT_NAME 	CHECK_CLAUSE 	LEVEL
+type TableCheckConstraintTuple = (String, String, Option<String>);
+
+//VIEW_DEFINITION 	CHECK_OPTION 	IS_UPDATABLE 	DEFINER 	SECURITY_TYPE
+type ViewTuple = (
+    String,
+    Option<String>,
+    String,
+    Option<String>,
+    Option<String>,
+);
+
+//TRIGGER_NAME 	EVENT_OBJECT_TABLE 	ACTION_TIMING 	EVENT_MANIPULATION 	ACTION_STATEMENT 	ACTION_ORDER 	DEFINER
+type TriggerTuple = (String, String, String, String, String, i64, Option<String>);
+
+//ROUTINE_NAME 	ROUTINE_TYPE 	DTD_IDENTIFIER 	ROUTINE_DEFINITION 	IS_DETERMINISTIC 	SQL_DATA_ACCESS 	SECURITY_TYPE 	DEFINER 	ROUTINE_COMMENT
+type RoutineTuple = (
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+//SPECIFIC_NAME 	ROUTINE_TYPE 	PARAMETER_MODE 	PARAMETER_NAME 	DTD_IDENTIFIER
+type RoutineParameterTuple = (String, String, Option<String>, Option<String>, String);
+
+//EVENT_NAME 	EVENT_DEFINITION 	EVENT_TYPE 	EXECUTE_AT 	INTERVAL_VALUE 	INTERVAL_FIELD 	STARTS 	ENDS 	STATUS 	ON_COMPLETION 	DEFINER 	EVENT_COMMENT
+type EventTuple = (
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
 type TableConstraintTuple = (String, String, String, Option<String>, Option<String>);
 
+//CONSTRAINT_NAME 	TABLE_SCHEMA 	TABLE_NAME 	COLUMN_NAME 	REFERENCED_TABLE_SCHEMA 	REFERENCED_TABLE_NAME 	REFERENCED_COLUMN_NAME
+type CrossDatabaseConstraintTuple = (
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+/// How to treat tables that are not using the InnoDB storage engine
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum EnginePolicy {
+    /// Reflect them like any other table
+    #[default]
+    Allow,
+    /// Reflect them, but record a warning
+    Warn,
+    /// Fail the reflection with a validation error
+    Reject,
+}
+
+/// Shape of information_schema.CHECK_CONSTRAINTS on the connected server
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+enum CheckConstraintSupport {
+    /// Missing before MariaDB 10.2.22 and MySQL 8.0.16
+    #[default]
+    Unavailable,
+    /// MySQL, tables are found through TABLE_CONSTRAINTS
+    WithoutTableName,
+    /// MariaDB, LEVEL tells column from table checks since 10.5.10
+    WithTableName { level: bool },
+}
+
+impl CheckConstraintSupport {
+    /// Pick the shape from the columns of information_schema.CHECK_CONSTRAINTS
+    fn from_columns(columns: &[String]) -> CheckConstraintSupport {
+        let has = |name: &str| columns.iter().any(|c| c.eq_ignore_ascii_case(name));
+
+        if columns.is_empty() {
+            CheckConstraintSupport::Unavailable
+        } else if has("TABLE_NAME") {
+            CheckConstraintSupport::WithTableName {
+                level: has("LEVEL"),
+            }
+        } else {
+            CheckConstraintSupport::WithoutTableName
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AdapterOptions {
+    engine_policy: EnginePolicy,
+    views_as_tables: bool,
+    tables_only: bool,
+    max_connections: u32,
+    concurrency: usize,
+    observer: Option<ReflectionObserver>,
+    cancellation: Option<CancellationToken>,
+    query_timeout: Option<Duration>,
+}
+
+impl Default for AdapterOptions {
+    fn default() -> Self {
+        AdapterOptions {
+            engine_policy: EnginePolicy::default(),
+            views_as_tables: false,
+            tables_only: false,
+            max_connections: 1,
+            concurrency: 1,
+            observer: None,
+            cancellation: None,
+            query_timeout: None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MariadbInnodbReflectionAdapter<T: State<MySql>> {
     state: T,
     connection_string: String,
     database_name: String,
+    options: AdapterOptions,
+    server: ServerInfo,
+    check_support: CheckConstraintSupport,
+    warnings: Arc<Mutex<Vec<String>>>,
 }
 
 impl MariadbInnodbReflectionAdapter<Uninitialized<MySql>> {
@@ -72,6 +253,124 @@ impl MariadbInnodbReflectionAdapter<Uninitialized<MySql>> {
             state: Uninitialized::new(),
             connection_string: connection_string.to_string(),
             database_name: String::new(),
+            options: AdapterOptions::default(),
+            server: ServerInfo::default(),
+            check_support: CheckConstraintSupport::default(),
+            warnings: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Set connection pool size, only before connecting as the pool is created on connect
+    pub fn set_max_connections(&mut self, max_connections: u32) -> &mut Self {
+        self.options.max_connections = max_connections.max(1);
+        self
+    }
+}
+
+impl<T: State<MySql>> MariadbInnodbReflectionAdapter<T> {
+    /// Set the policy for tables that are not using InnoDB
+    pub fn set_engine_policy(&mut self, engine_policy: EnginePolicy) -> &mut Self {
+        self.options.engine_policy = engine_policy;
+        self
+    }
+
+    /// Get the policy for tables that are not using InnoDB
+    pub fn engine_policy(&self) -> EnginePolicy {
+        self.options.engine_policy
+    }
+
+    /// Set whether views should be reflected as tables instead of views
+    pub fn set_views_as_tables(&mut self, views_as_tables: bool) -> &mut Self {
+        self.options.views_as_tables = views_as_tables;
+        self
+    }
+
+    /// Get whether views are reflected as tables instead of views
+    pub fn views_as_tables(&self) -> bool {
+        self.options.views_as_tables
+    }
+
+    /// Set whether only tables and their constraints are reflected,
+    /// skipping views, triggers, routines and events
+    pub fn set_tables_only(&mut self, tables_only: bool) -> &mut Self {
+        self.options.tables_only = tables_only;
+        self
+    }
+
+    /// Get whether only tables and their constraints are reflected
+    pub fn tables_only(&self) -> bool {
+        self.options.tables_only
+    }
+
+    /// Get connection pool size
+    pub fn max_connections(&self) -> u32 {
+        self.options.max_connections
+    }
+
+    /// Set how many tables and views are reflected at the same time
+    pub fn set_concurrency(&mut self, concurrency: usize) -> &mut Self {
+        self.options.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Get how many tables and views are reflected at the same time
+    pub fn concurrency(&self) -> usize {
+        self.options.concurrency
+    }
+
+    /// Get server flavor and version, detected on connect
+    pub fn server_info(&self) -> &ServerInfo {
+        &self.server
+    }
+
+    /// Get warnings collected during the last reflection
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+
+    fn warn(&self, warning: String) {
+        self.notify(ReflectionEvent::Warning(warning.clone()));
+        if let Ok(mut warnings) = self.warnings.lock() {
+            warnings.push(warning);
+        }
+    }
+
+    fn notify(&self, event: ReflectionEvent) {
+        if let Some(observer) = &self.options.observer {
+            observer.notify(&event);
+        }
+    }
+
+    fn check_cancelled(&self) -> Result<(), ReflectionAdapterError> {
+        match &self.options.cancellation {
+            Some(token) if token.is_cancelled() => Err(ReflectionAdapterError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Run a query, honouring the per-query timeout and aborting it on cancellation
+    async fn guard<R>(
+        &self,
+        query: impl Future<Output = Result<R, sqlx::Error>>,
+    ) -> Result<R, ReflectionAdapterError> {
+        self.check_cancelled()?;
+
+        let query = async {
+            match self.options.query_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, query)
+                    .await
+                    .map_err(|_| ReflectionAdapterError::Timeout(timeout))?
+                    .map_err(DatabaseError),
+                None => query.await.map_err(DatabaseError),
+            }
+        };
+
+        match &self.options.cancellation {
+            Some(token) => match select(pin!(query), pin!(token.cancelled())).await {
+                Either::Left((result, _)) => result,
+                Either::Right(_) => Err(ReflectionAdapterError::Cancelled),
+            },
+            None => query.await,
         }
     }
 }
@@ -89,7 +388,7 @@ impl ReflectionAdapterUninitialized<MySql>
         self,
     ) -> Result<MariadbInnodbReflectionAdapter<Connected<MySql>>, ReflectionAdapterError> {
         let pool = MySqlPoolOptions::new()
-            .max_connections(1)
+            .max_connections(self.options.max_connections)
             .connect(&self.connection_string)
             .await
             .map_err(ReflectionAdapterError::ConnectionError)?;
@@ -99,10 +398,33 @@ impl ReflectionAdapterUninitialized<MySql>
             .await
             .unwrap_or_default();
 
+        // the flavor decides how columns and checks are read, so don't guess it
+        let server_version: String = sqlx::query_scalar("SELECT VERSION()")
+            .fetch_one(&pool)
+            .await
+            .map_err(DatabaseError)?;
+
+        let check_columns: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT 
+                COLUMN_NAME 
+            FROM information_schema.`COLUMNS` 
+            WHERE TABLE_SCHEMA = 'information_schema' 
+            AND TABLE_NAME = 'CHECK_CONSTRAINTS'
+            "#,
+        )
+        .fetch_all(&pool)
+        .await
+        .map_err(DatabaseError)?;
+
         Ok(MariadbInnodbReflectionAdapter::<Connected<MySql>> {
             state: Connected::new(pool),
             connection_string: self.connection_string,
             database_name,
+            options: self.options,
+            server: ServerInfo::parse(&server_version),
+            check_support: CheckConstraintSupport::from_columns(&check_columns),
+            warnings: self.warnings,
         })
     }
 }
@@ -111,128 +433,443 @@ impl MariadbInnodbReflectionAdapter<Connected<MySql>> {
     pub fn get_connection(&self) -> &Pool<MySql> {
         &self.state
     }
-}
-
-impl ReflectionAdapter<MySql> for MariadbInnodbReflectionAdapter<Connected<MySql>> {
-    type InvalidAdapter = MariadbInnodbReflectionAdapter<Uninitialized<MySql>>;
 
-    async fn disconnect(
-        self,
-    ) -> Result<MariadbInnodbReflectionAdapter<Uninitialized<MySql>>, ReflectionAdapterError> {
-        self.get_connection().close().await;
+    /// Copy of the adapter sharing the same pool, targeting another database
+    fn with_database_name(&self, database_name: &str) -> Self {
+        MariadbInnodbReflectionAdapter::<Connected<MySql>> {
+            state: Connected::new(self.get_connection().clone()),
+            connection_string: self.connection_string.clone(),
+            database_name: database_name.to_string(),
+            options: self.options.clone(),
+            server: self.server.clone(),
+            check_support: self.check_support,
+            warnings: self.warnings.clone(),
+        }
+    }
 
-        Ok(MariadbInnodbReflectionAdapter::new(&self.connection_string))
+    fn clear_warnings(&self) {
+        if let Ok(mut warnings) = self.warnings.lock() {
+            warnings.clear();
+        }
     }
 
-    async fn set_database_name(
-        &mut self,
-        database_name: &str,
-    ) -> Result<(), ReflectionAdapterError> {
-        sqlx::query(format!("USE  {}", &database_name).as_str())
-            .execute(&*self.state)
-            .await
-            .map_err(DatabaseError)?;
+    /// Get create time, update time and definition checksum of tables, optionally only one.
+    /// The checksum covers columns, indexes, table options, CHECK constraints, partitions
+    /// and foreign keys.
+    async fn get_table_stamps(
+        &self,
+        table_name: Option<&str>,
+    ) -> Result<HashMap<String, TableStamp>, ReflectionAdapterError> {
+        let mut parts = vec![
+            (
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'table', TABLE_COMMENT, ENGINE, ROW_FORMAT, CREATE_OPTIONS, TABLE_COLLATION)) AS PART_CHECKSUM \
+                FROM information_schema.`TABLES` \
+                WHERE TABLE_SCHEMA = ?",
+                "TABLE_NAME",
+            ),
+            (
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'column', COLUMN_NAME, ORDINAL_POSITION, COLUMN_TYPE, IS_NULLABLE, COLUMN_DEFAULT, EXTRA, COLUMN_COMMENT, COLLATION_NAME)) AS PART_CHECKSUM \
+                FROM information_schema.`COLUMNS` \
+                WHERE TABLE_SCHEMA = ?",
+                "TABLE_NAME",
+            ),
+            (
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'index', INDEX_NAME, SEQ_IN_INDEX, COLUMN_NAME, NON_UNIQUE)) AS PART_CHECKSUM \
+                FROM information_schema.`STATISTICS` \
+                WHERE TABLE_SCHEMA = ?",
+                "TABLE_NAME",
+            ),
+            (
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'partition', PARTITION_NAME, PARTITION_METHOD, PARTITION_EXPRESSION, PARTITION_DESCRIPTION, SUBPARTITION_NAME, SUBPARTITION_METHOD, SUBPARTITION_EXPRESSION)) AS PART_CHECKSUM \
+                FROM information_schema.`PARTITIONS` \
+                WHERE TABLE_SCHEMA = ?",
+                "TABLE_NAME",
+            ),
+            (
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'foreign', CONSTRAINT_NAME, COLUMN_NAME, REFERENCED_TABLE_SCHEMA, REFERENCED_TABLE_NAME, REFERENCED_COLUMN_NAME, POSITION_IN_UNIQUE_CONSTRAINT)) AS PART_CHECKSUM \
+                FROM information_schema.`KEY_COLUMN_USAGE` \
+                WHERE TABLE_SCHEMA = ? \
+                AND REFERENCED_TABLE_NAME IS NOT NULL",
+                "TABLE_NAME",
+            ),
+            (
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'action', CONSTRAINT_NAME, UPDATE_RULE, DELETE_RULE)) AS PART_CHECKSUM \
+                FROM information_schema.`REFERENTIAL_CONSTRAINTS` \
+                WHERE CONSTRAINT_SCHEMA = ?",
+                "TABLE_NAME",
+            ),
+        ];
 
-        self.database_name = database_name.to_string();
+        match self.check_support {
+            CheckConstraintSupport::Unavailable => {}
+            CheckConstraintSupport::WithTableName { .. } => parts.push((
+                "SELECT TABLE_NAME, CRC32(CONCAT_WS('|', 'check', CONSTRAINT_NAME, CHECK_CLAUSE)) AS PART_CHECKSUM \
+                FROM information_schema.`CHECK_CONSTRAINTS` \
+                WHERE CONSTRAINT_SCHEMA = ?",
+                "TABLE_NAME",
+            )),
+            CheckConstraintSupport::WithoutTableName => parts.push((
+                "SELECT tc.TABLE_NAME, CRC32(CONCAT_WS('|', 'check', cc.CONSTRAINT_NAME, cc.CHECK_CLAUSE)) AS PART_CHECKSUM \
+                FROM information_schema.`CHECK_CONSTRAINTS` cc \
+                JOIN information_schema.`TABLE_CONSTRAINTS` tc \
+                ON tc.CONSTRAINT_SCHEMA = cc.CONSTRAINT_SCHEMA \
+                AND tc.CONSTRAINT_NAME = cc.CONSTRAINT_NAME \
+                WHERE tc.CONSTRAINT_SCHEMA = ? \
+                AND tc.CONSTRAINT_TYPE = 'CHECK'",
+                "tc.TABLE_NAME",
+            )),
+        }
 
-        Ok(())
+        // every part is filtered, so stamping one table doesn't aggregate the whole schema,
+        // the values are bound in the order their placeholders appear
+        let mut binds: Vec<&str> = Vec::new();
+        let mut filtered = |part: &str, table_column: &str| {
+            binds.push(&self.database_name);
+
+            match table_name {
+                Some(table_name) => {
+                    binds.push(table_name);
+                    format!("{} AND {} = ?", part, table_column)
+                }
+                None => part.to_string(),
+            }
+        };
+
+        let union = parts
+            .iter()
+            .map(|(part, table_column)| filtered(part, table_column))
+            .collect::<Vec<String>>()
+            .join(" UNION ALL ");
+        let outer = filtered("WHERE T.TABLE_SCHEMA = ?", "T.TABLE_NAME");
+
+        let query = format!(
+            r#"
+            SELECT 
+                T.TABLE_NAME, 
+                CAST(T.CREATE_TIME AS CHAR), 
+                CAST(T.UPDATE_TIME AS CHAR), 
+                CAST(SUM(P.PART_CHECKSUM) AS CHAR) 
+            FROM information_schema.`TABLES` T 
+            LEFT JOIN ({}) P ON P.TABLE_NAME = T.TABLE_NAME 
+            {} 
+            GROUP BY T.TABLE_NAME, T.CREATE_TIME, T.UPDATE_TIME
+            "#,
+            union, outer
+        );
+
+        let mut query = sqlx::query_as(&query);
+        for bind in binds {
+            query = query.bind(bind);
+        }
+
+        let stamps: Vec<TableStampTuple> =
+            self.guard(query.fetch_all(self.get_connection())).await?;
+
+        Ok(stamps
+            .into_iter()
+            .map(|(name, create_time, update_time, checksum)| {
+                (
+                    name,
+                    TableStamp {
+                        create_time,
+                        update_time,
+                        checksum,
+                    },
+                )
+            })
+            .collect())
     }
 
-    fn get_database_name(&self) -> &str {
-        &self.database_name
+    /// Reflect the current database without resetting collected warnings,
+    /// reusing tables from a previous reflection when their stamps are unchanged
+    async fn get_database_reflection(
+        &self,
+        previous: Option<&Database>,
+    ) -> Result<(Database, Vec<TableChange>), ReflectionAdapterError> {
+        let mut db = Database::new(&self.database_name);
+        self.notify(ReflectionEvent::DatabaseStarted(self.database_name.clone()));
+
+        let table_names = self.list_table_names().await?;
+        let mut changes = Vec::new();
+
+        // stamp every table in one query, so a later refresh can tell which ones changed
+        let mut stamps = self.get_table_stamps(None).await?;
+
+        // decide which tables can be reused as they are
+        let mut reusable: HashMap<String, Table> = HashMap::new();
+        if let Some(previous) = previous {
+            let (detected, unchanged) = detect_table_changes(previous, &table_names, &stamps);
+            changes = detected;
+
+            for table_name in unchanged {
+                if let Some(table) = previous.table(&table_name) {
+                    reusable.insert(table_name, (*table).clone());
+                }
+            }
+
+            for (key, value) in metadata_entries(previous) {
+                db.set_meta_value(key, value);
+            }
+            db.set_charset(previous.charset())
+                .set_collation(previous.collation());
+        }
+
+        // collect tables, in listing order regardless of which finishes first
+        let reusable = &reusable;
+        let tables: Vec<Table> = stream::iter(table_names)
+            .map(|table_name| async move {
+                if let Some(table) = reusable.get(&table_name) {
+                    return Ok(table.clone());
+                }
+
+                self.notify(ReflectionEvent::TableStarted(table_name.clone()));
+                let table = self.get_table_reflection(&table_name).await?;
+                self.notify(ReflectionEvent::TableFinished(table_name));
+
+                Ok::<Table, ReflectionAdapterError>(table)
+            })
+            .buffered(self.options.concurrency)
+            .try_collect()
+            .await?;
+        for mut table in tables {
+            let table_name = table.name();
+
+            if let Some(stamp) = stamps.remove(table_name.as_str()) {
+                table
+                    .set_create_time(stamp.create_time)
+                    .set_update_time(stamp.update_time)
+                    .set_checksum(stamp.checksum);
+            }
+
+            if let Some(engine) = table.engine() {
+                if !engine.eq_ignore_ascii_case(ENGINE_INNODB) {
+                    let message = format!(
+                        "Table {} uses the {} storage engine instead of {}",
+                        table_name, engine, ENGINE_INNODB
+                    );
+
+                    match self.options.engine_policy {
+                        EnginePolicy::Allow => {}
+                        EnginePolicy::Warn => self.warn(message),
+                        EnginePolicy::Reject => {
+                            return Err(ReflectionAdapterError::ValidationError(message))
+                        }
+                    }
+                }
+            }
+
+            db.set_table(table);
+        }
+
+        // collect views, unless they were already reflected as tables
+        let view_names = if self.options.views_as_tables || self.options.tables_only {
+            Vec::new()
+        } else {
+            self.list_view_names().await?
+        };
+        let views: Vec<View> = stream::iter(view_names)
+            .map(|view_name| async move {
+                self.notify(ReflectionEvent::ViewStarted(view_name.clone()));
+                let view = self.get_view_reflection(&view_name).await?;
+                self.notify(ReflectionEvent::ViewFinished(view_name));
+
+                Ok::<View, ReflectionAdapterError>(view)
+            })
+            .buffered(self.options.concurrency)
+            .try_collect()
+            .await?;
+        for view in views {
+            db.set_view(view);
+        }
+
+        // collect stored programs
+        if !self.options.tables_only {
+            for trigger in self.get_trigger_reflections().await? {
+                db.set_trigger(trigger);
+            }
+
+            for routine in self.get_routine_reflections().await? {
+                db.set_routine(routine);
+            }
+
+            for event in self.get_event_reflections().await? {
+                db.set_event(event);
+            }
+        }
+
+        // collect constraints
+        let foreign_keys = self.get_foreign_keys(None).await?;
+        add_foreign_keys(&mut db, foreign_keys);
+
+        self.notify(ReflectionEvent::ConstraintsLoaded(db.constraints().count()));
+        self.notify(ReflectionEvent::DatabaseFinished(
+            self.database_name.clone(),
+        ));
+
+        Ok((db, changes))
     }
 
-    async fn list_database_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
-        sqlx::query_scalar("SHOW DATABASES")
-            .fetch_all(self.get_connection())
-            .await
-            .map_err(DatabaseError)
+    /// Reflect a table together with the tables it references or is referenced by,
+    /// and the foreign keys between them
+    pub async fn get_related_reflection(
+        &self,
+        table_name: &str,
+    ) -> Result<Database, ReflectionAdapterError> {
+        self.clear_warnings();
+
+        let mut db = Database::new(&self.database_name);
+        db.set_table(self.get_table_reflection(table_name).await?);
+
+        let foreign_keys = self.get_foreign_keys(Some(table_name)).await?;
+        for (_, local_table_name, _, foreign_table_name, _) in foreign_keys.iter() {
+            for related in [Some(local_table_name), foreign_table_name.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                if db.table(related).is_none() {
+                    db.set_table(self.get_table_reflection(related).await?);
+                }
+            }
+        }
+
+        add_foreign_keys(&mut db, foreign_keys);
+
+        Ok(db)
     }
 
-    async fn list_table_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
-        sqlx::query_scalar("SHOW TABLES")
-            .fetch_all(self.get_connection())
-            .await
-            .map_err(DatabaseError)
+    /// Get foreign key columns within the database, optionally only those
+    /// of one table or referencing it
+    async fn get_foreign_keys(
+        &self,
+        table_name: Option<&str>,
+    ) -> Result<Vec<TableConstraintTuple>, ReflectionAdapterError> {
+        let filter = if table_name.is_some() {
+            "AND (TABLE_NAME = ? OR REFERENCED_TABLE_NAME = ?)"
+        } else {
+            ""
+        };
+
+        let query = format!(
+            r#"
+        SELECT
+          CONSTRAINT_NAME, TABLE_NAME, COLUMN_NAME, REFERENCED_TABLE_NAME, REFERENCED_COLUMN_NAME
+        FROM
+          INFORMATION_SCHEMA.KEY_COLUMN_USAGE
+        WHERE
+          TABLE_SCHEMA = '{}' AND
+          CONSTRAINT_NAME != 'PRIMARY' AND
+          REFERENCED_TABLE_SCHEMA = TABLE_SCHEMA
+          {}
+        ORDER BY CONSTRAINT_NAME ASC, POSITION_IN_UNIQUE_CONSTRAINT ASC
+        "#,
+            &self.database_name, filter
+        );
+
+        let mut query = sqlx::query_as(&query);
+        if let Some(table_name) = table_name {
+            query = query.bind(table_name).bind(table_name);
+        }
+
+        self.guard(query.fetch_all(self.get_connection())).await
     }
 
-    async fn get_table_reflection(
+    /// Reflect the columns of a table or a view
+    async fn get_column_reflections(
         &self,
         table_name: &str,
-    ) -> Result<Table, ReflectionAdapterError> {
-        let mut table = Table::new(table_name);
+    ) -> Result<Vec<Column>, ReflectionAdapterError> {
+        let mut columns = Vec::new();
 
-        let default_table_character_set_and_collation: (Option<String>, Option<String>) = sqlx::query_as(&format!(r#"
+        let column_details: HashMap<String, TableColumnDetailsTuple> = self
+            .guard(
+                sqlx::query_as::<_, TableColumnDetailsTuple>(&format!(
+                    r#"
             SELECT 
-                CCSA.CHARACTER_SET_NAME, 
-                CCSA.COLLATION_NAME 
-            FROM information_schema.`TABLES` T, information_schema.`COLLATION_CHARACTER_SET_APPLICABILITY` CCSA 
-            WHERE CCSA.COLLATION_NAME = T.TABLE_COLLATION 
-            AND T.TABLE_SCHEMA = '{}' 
-            AND T.TABLE_NAME = '{}'
-            "#, &self.database_name, table_name))
-            .fetch_one(self.get_connection())
-            .await
-            .map_err(DatabaseError)?;
-        if let (Some(charset), Some(collation)) = default_table_character_set_and_collation {
-            table
-                .set_meta(METADATA_CHARSET, charset)
-                .set_meta(METADATA_COLLATION, collation);
-        }
+                COLUMN_NAME, 
+                CHARACTER_SET_NAME, 
+                COLLATION_NAME, 
+                COLUMN_DEFAULT, 
+                GENERATION_EXPRESSION 
+            FROM information_schema.`COLUMNS` 
+            WHERE table_schema = '{}' 
+            AND table_name = '{}'
+            "#,
+                    &self.database_name, table_name
+                ))
+                .fetch_all(self.get_connection()),
+            )
+            .await?
+            .into_iter()
+            .map(|row| (row.0.clone(), row))
+            .collect();
 
-        let table_columns: Vec<TableComlumnsTuple> =
-            sqlx::query_as(format!("SHOW COLUMNS FROM {}", table_name).as_str())
-                .fetch_all(self.get_connection())
-                .await
-                .map_err(DatabaseError)?;
+        let table_columns: Vec<TableComlumnsTuple> = self
+            .guard(
+                sqlx::query_as(
+                    format!(
+                        "SHOW FULL COLUMNS FROM `{}` FROM `{}`",
+                        table_name, &self.database_name
+                    )
+                    .as_str(),
+                )
+                .fetch_all(self.get_connection()),
+            )
+            .await?;
         for table_column in table_columns {
-            let (field_name, field_type, field_nullable, field_key, field_default, field_extra) =
-                table_column;
+            let (
+                field_name,
+                field_type,
+                _field_collation,
+                field_nullable,
+                field_key,
+                _field_default,
+                field_extra,
+                _field_privileges,
+                field_comment,
+            ) = table_column;
 
             let mut col = Column::new(
-                table.name().as_ref(),
+                table_name,
                 field_name.as_str(),
                 SqlDatatype::try_from(field_type.as_str()).unwrap_or(SqlDatatype::default()),
             );
 
+            let (_, column_charset, column_collation, column_default, generation_expression) =
+                column_details.get(&field_name).cloned().unwrap_or_default();
+            // MySQL reports an empty generation expression for regular columns
+            let generation_expression = generation_expression.filter(|e| !e.is_empty());
+            let field_extra = field_extra.map(|e| self.server.normalize_extra(&e));
+            let column_default = self.server.normalize_default(
+                column_default.as_deref(),
+                field_extra.as_deref().unwrap_or_default(),
+                field_nullable == "YES",
+                col.datatype(),
+            );
+
             if let SqlDatatype::Char(_) | SqlDatatype::Varchar(_) | SqlDatatype::Text(_) =
                 col.datatype()
             {
-                let default_column_character_set_and_collation: (Option<String>, Option<String>) =
-                    sqlx::query_as(&format!(
-                        r#"
-                    SELECT 
-                        CHARACTER_SET_NAME, 
-                        COLLATION_NAME 
-                    FROM information_schema.`COLUMNS` 
-                    WHERE table_schema = '{}' 
-                    AND table_name = '{}' 
-                    AND column_name = '{}'
-                    "#,
-                        &self.database_name, table_name, &field_name
-                    ))
-                    .fetch_one(self.get_connection())
-                    .await
-                    .map_err(DatabaseError)?;
-
-                if let (Some(charset), Some(collation)) = default_column_character_set_and_collation
-                {
-                    col.set_meta(METADATA_CHARSET, charset)
-                        .set_meta(METADATA_COLLATION, collation);
+                if let (Some(charset), Some(collation)) = (column_charset, column_collation) {
+                    col.set_charset(Some(charset))
+                        .set_collation(Some(collation));
                 }
             }
 
+            if !field_comment.is_empty() {
+                col.set_comment(Some(field_comment));
+            }
+
             if field_nullable == "YES" {
-                col.set_meta_flag(METADATA_FLAG_NULLABLE);
+                col.set_nullable(true);
             }
 
             if let Some(key_designation) = field_key {
                 match key_designation.as_str() {
                     "PRI" => {
-                        col.set_meta_flag(METADATA_FLAG_PRIMARY);
+                        col.set_primary(true);
                     }
                     "UNI" => {
-                        col.set_meta_flag(METADATA_FLAG_UNIQUE);
+                        col.set_unique(true);
 
                         //dig out index
 
@@ -247,43 +884,258 @@ impl ReflectionAdapter<MySql> for MariadbInnodbReflectionAdapter<Connected<MySql
                 }
             }
 
-            if let Some(default_value) = field_default {
+            if let Some(default_value) = column_default {
                 if col.datatype() == &SqlDatatype::Timestamp
                     && default_value == METADATA_FLAG_DEFAULT_CURRENT_TIMESTAMP
                 {
                     col.set_meta_flag(METADATA_FLAG_DEFAULT_CURRENT_TIMESTAMP);
                 }
 
-                col.set_default(Some(DefaultValue::Value(Value::from(default_value))));
+                let default_value = DefaultValue::from_sql(&default_value, col.datatype());
+                col.set_default(Some(default_value));
             }
 
             if let Some(extra) = field_extra {
                 if !extra.is_empty() {
-                    if extra.as_str() == METADATA_FLAG_AUTO_INCREMENT {
-                        col.set_meta_flag(METADATA_FLAG_AUTO_INCREMENT);
-                    } else if extra.as_str() == METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP {
-                        col.set_meta_flag(METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP);
+                    if extra.contains(METADATA_FLAG_AUTO_INCREMENT) {
+                        col.set_auto_increment(true);
+                    } else if let Some((_, on_update)) = extra.split_once("on update ") {
+                        // current_timestamp() or current_timestamp(n) with fractional seconds
+                        col.set_on_update(on_update.split_whitespace().next());
+                    }
+
+                    let generated_kind = if extra.contains("virtual generated") {
+                        Some(GeneratedKind::Virtual)
+                    } else if extra.contains("stored generated")
+                        || extra.contains("persistent generated")
+                    {
+                        Some(GeneratedKind::Stored)
+                    } else {
+                        None
+                    };
+
+                    if let Some(generated_kind) = generated_kind {
+                        col.set_generated(
+                            generated_kind,
+                            generation_expression.unwrap_or_default(),
+                        );
+                    }
+
+                    if extra.split_whitespace().any(|e| e == "invisible") {
+                        col.set_invisible(true);
                     }
                 }
             }
 
+            columns.push(col);
+        }
+
+        Ok(columns)
+    }
+}
+
+impl ReflectionAdapter<MySql> for MariadbInnodbReflectionAdapter<Connected<MySql>> {
+    type InvalidAdapter = MariadbInnodbReflectionAdapter<Uninitialized<MySql>>;
+
+    fn set_observer(&mut self, observer: Option<ReflectionObserver>) {
+        self.options.observer = observer;
+    }
+
+    fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.options.cancellation = token;
+    }
+
+    fn set_query_timeout(&mut self, timeout: Option<Duration>) {
+        self.options.query_timeout = timeout;
+    }
+
+    async fn disconnect(
+        self,
+    ) -> Result<MariadbInnodbReflectionAdapter<Uninitialized<MySql>>, ReflectionAdapterError> {
+        self.get_connection().close().await;
+
+        Ok(MariadbInnodbReflectionAdapter::<Uninitialized<MySql>> {
+            state: Uninitialized::new(),
+            connection_string: self.connection_string,
+            database_name: String::new(),
+            options: self.options,
+            server: ServerInfo::default(),
+            check_support: CheckConstraintSupport::default(),
+            warnings: self.warnings,
+        })
+    }
+
+    async fn set_database_name(
+        &mut self,
+        database_name: &str,
+    ) -> Result<(), ReflectionAdapterError> {
+        self.guard(sqlx::query(format!("USE  {}", &database_name).as_str()).execute(&*self.state))
+            .await?;
+
+        self.database_name = database_name.to_string();
+
+        Ok(())
+    }
+
+    fn get_database_name(&self) -> &str {
+        &self.database_name
+    }
+
+    async fn list_database_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
+        self.guard(sqlx::query_scalar("SHOW DATABASES").fetch_all(self.get_connection()))
+            .await
+    }
+
+    async fn list_table_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
+        let query = if self.options.views_as_tables {
+            format!("SHOW TABLES FROM `{}`", &self.database_name)
+        } else {
+            format!(
+                "SHOW FULL TABLES FROM `{}` WHERE Table_type <> 'VIEW'",
+                &self.database_name
+            )
+        };
+
+        self.guard(sqlx::query_scalar(&query).fetch_all(self.get_connection()))
+            .await
+    }
+
+    async fn list_view_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
+        self.guard(
+            sqlx::query_scalar(&format!(
+                "SHOW FULL TABLES FROM `{}` WHERE Table_type = 'VIEW'",
+                &self.database_name
+            ))
+            .fetch_all(self.get_connection()),
+        )
+        .await
+    }
+
+    async fn get_table_reflection(
+        &self,
+        table_name: &str,
+    ) -> Result<Table, ReflectionAdapterError> {
+        let mut table = Table::new(table_name);
+
+        let table_options: TableOptionsTuple = self.guard(sqlx::query_as(&format!(r#"
+            SELECT 
+                CCSA.CHARACTER_SET_NAME, 
+                CCSA.COLLATION_NAME, 
+                T.TABLE_COMMENT, 
+                T.ENGINE, 
+                T.ROW_FORMAT, 
+                T.AUTO_INCREMENT, 
+                T.CREATE_OPTIONS 
+            FROM information_schema.`TABLES` T 
+            LEFT JOIN information_schema.`COLLATION_CHARACTER_SET_APPLICABILITY` CCSA ON CCSA.COLLATION_NAME = T.TABLE_COLLATION 
+            WHERE T.TABLE_SCHEMA = '{}' 
+            AND T.TABLE_NAME = '{}'
+            "#, &self.database_name, table_name))
+            .fetch_one(self.get_connection())).await?;
+        let (
+            table_charset,
+            table_collation,
+            table_comment,
+            table_engine,
+            table_row_format,
+            table_auto_increment,
+            table_create_options,
+        ) = table_options;
+        if let (Some(charset), Some(collation)) = (table_charset, table_collation) {
+            table
+                .set_charset(Some(charset))
+                .set_collation(Some(collation));
+        }
+        table
+            .set_comment(table_comment.filter(|c| !c.is_empty()))
+            .set_engine(table_engine)
+            .set_row_format(table_row_format)
+            .set_auto_increment(table_auto_increment)
+            .set_create_options(table_create_options.filter(|c| !c.is_empty()));
+
+        let table_partitions: Vec<TablePartitionTuple> = self
+            .guard(
+                sqlx::query_as(&format!(
+                    r#"
+            SELECT 
+                PARTITION_NAME, 
+                PARTITION_METHOD, 
+                PARTITION_EXPRESSION, 
+                PARTITION_DESCRIPTION, 
+                SUBPARTITION_NAME, 
+                SUBPARTITION_METHOD, 
+                SUBPARTITION_EXPRESSION 
+            FROM information_schema.`PARTITIONS` 
+            WHERE TABLE_SCHEMA = '{}' 
+            AND TABLE_NAME = '{}' 
+            AND PARTITION_NAME IS NOT NULL 
+            ORDER BY PARTITION_ORDINAL_POSITION ASC, SUBPARTITION_ORDINAL_POSITION ASC
+            "#,
+                    &self.database_name, table_name
+                ))
+                .fetch_all(self.get_connection()),
+            )
+            .await?;
+        for table_partition in table_partitions {
+            let (
+                partition_name,
+                partition_method,
+                partition_expression,
+                partition_description,
+                subpartition_name,
+                subpartition_method,
+                subpartition_expression,
+            ) = table_partition;
+
+            let mut partition = if let Some(partition) = table.partition(&partition_name) {
+                partition.clone()
+            } else {
+                Partition::new(partition_name, partition_method.unwrap_or_default())
+                    .set_expression(partition_expression)
+                    .set_description(partition_description)
+                    .set_subpartitioning(subpartition_method, subpartition_expression)
+                    .to_owned()
+            };
+
+            if let Some(subpartition_name) = subpartition_name {
+                partition.add_subpartition(subpartition_name);
+            }
+
+            table.set_partition(partition);
+        }
+
+        for col in self.get_column_reflections(table_name).await? {
             table.set_column(col);
         }
 
         //Table 	Non_unique 	Key_name 	Seq_in_index 	Column_name 	Collation 	Cardinality 	Sub_part 	Packed 	Null 	Index_type 	Comment 	Index_comment 	Ignored
-        let table_indexes: Vec<TableIndexTuple> = if let Ok(table_indexes) =
-            sqlx::query_as(format!("SHOW INDEXES FROM {}", table_name).as_str())
-                .fetch_all(self.get_connection())
-                .await
-                .map_err(DatabaseError)
+        let table_indexes: Vec<TableIndexTuple> = if let Ok(table_indexes) = self
+            .guard(
+                sqlx::query_as(
+                    format!(
+                        "SHOW INDEXES FROM `{}` FROM `{}`",
+                        table_name, &self.database_name
+                    )
+                    .as_str(),
+                )
+                .fetch_all(self.get_connection()),
+            )
+            .await
         {
             table_indexes
         } else {
-            let table_indexes: Vec<TableIndexTupleAlt> =
-                sqlx::query_as(format!("SHOW INDEXES FROM {}", table_name).as_str())
-                    .fetch_all(self.get_connection())
-                    .await
-                    .map_err(DatabaseError)?;
+            let table_indexes: Vec<TableIndexTupleAlt> = self
+                .guard(
+                    sqlx::query_as(
+                        format!(
+                            "SHOW INDEXES FROM `{}` FROM `{}`",
+                            table_name, &self.database_name
+                        )
+                        .as_str(),
+                    )
+                    .fetch_all(self.get_connection()),
+                )
+                .await?;
 
             table_indexes
                 .into_iter()
@@ -311,9 +1163,9 @@ impl ReflectionAdapter<MySql> for MariadbInnodbReflectionAdapter<Connected<MySql
         for table_index in table_indexes {
             let (
                 _index_table_name,
-                index_unique,
+                index_non_unique,
                 index_name,
-                _index_sequence,
+                index_sequence,
                 index_column,
                 _index_collation,
                 _index_cardinality,
@@ -328,84 +1180,453 @@ impl ReflectionAdapter<MySql> for MariadbInnodbReflectionAdapter<Connected<MySql
 
             if let Some(col) = table.column(&index_column) {
                 let primary = index_name == "PRIMARY";
-                let indx = Index::new(index_name, col, primary, index_unique);
 
-                table.set_index(indx);
+                table.add_index_column(
+                    index_name,
+                    col,
+                    index_sequence as usize,
+                    primary,
+                    !index_non_unique,
+                );
             }
         }
 
+        let check_constraints_query = match self.check_support {
+            CheckConstraintSupport::Unavailable => None,
+            CheckConstraintSupport::WithTableName { level } => Some(format!(
+                r#"
+            SELECT 
+                CONSTRAINT_NAME, 
+                CHECK_CLAUSE, 
+                {} 
+            FROM information_schema.`CHECK_CONSTRAINTS` 
+            WHERE CONSTRAINT_SCHEMA = '{}' 
+            AND TABLE_NAME = '{}' 
+            ORDER BY CONSTRAINT_NAME ASC
+            "#,
+                if level { "LEVEL" } else { "CAST(NULL AS CHAR)" },
+                &self.database_name,
+                table_name
+            )),
+            // MySQL has no TABLE_NAME in CHECK_CONSTRAINTS
+            CheckConstraintSupport::WithoutTableName => Some(format!(
+                r#"
+            SELECT 
+                cc.CONSTRAINT_NAME, 
+                cc.CHECK_CLAUSE, 
+                CAST(NULL AS CHAR) 
+            FROM information_schema.`CHECK_CONSTRAINTS` cc 
+            JOIN information_schema.`TABLE_CONSTRAINTS` tc 
+            ON tc.CONSTRAINT_SCHEMA = cc.CONSTRAINT_SCHEMA 
+            AND tc.CONSTRAINT_NAME = cc.CONSTRAINT_NAME 
+            WHERE tc.CONSTRAINT_SCHEMA = '{}' 
+            AND tc.TABLE_NAME = '{}' 
+            AND tc.CONSTRAINT_TYPE = 'CHECK' 
+            ORDER BY cc.CONSTRAINT_NAME ASC
+            "#,
+                &self.database_name, table_name
+            )),
+        };
+
+        let table_check_constraints: Vec<TableCheckConstraintTuple> = match check_constraints_query
+        {
+            Some(query) => {
+                self.guard(sqlx::query_as(&query).fetch_all(self.get_connection()))
+                    .await?
+            }
+            None => Vec::new(),
+        };
+        for table_check_constraint in table_check_constraints {
+            let (check_name, check_clause, check_level) = table_check_constraint;
+            let check_clause = self.server.normalize_check_clause(&check_clause);
+
+            // MariaDB names column level constraints after their column
+            let check_column = match check_level.as_deref() {
+                Some("Column") => table.column(&check_name).map(|c| c.name()),
+                _ => None,
+            };
+
+            table.set_check_constraint(
+                CheckConstraint::new(check_name, table_name, check_clause)
+                    .set_column(check_column)
+                    .to_owned(),
+            );
+        }
+
         Ok(table)
     }
 
-    async fn get_reflection(&self) -> Result<Database, ReflectionAdapterError> {
-        let mut db = Database::new(&self.database_name);
+    async fn get_view_reflection(&self, view_name: &str) -> Result<View, ReflectionAdapterError> {
+        let view_info: ViewTuple = self
+            .guard(
+                sqlx::query_as(&format!(
+                    r#"
+            SELECT 
+                VIEW_DEFINITION, 
+                CHECK_OPTION, 
+                IS_UPDATABLE, 
+                DEFINER, 
+                SECURITY_TYPE 
+            FROM information_schema.`VIEWS` 
+            WHERE TABLE_SCHEMA = '{}' 
+            AND TABLE_NAME = '{}'
+            "#,
+                    &self.database_name, view_name
+                ))
+                .fetch_one(self.get_connection()),
+            )
+            .await?;
 
-        // collect tables
-        let tables = self.list_table_names().await?;
-        for table_name in tables {
-            let table = self.get_table_reflection(&table_name).await?;
+        let (view_definition, view_check_option, view_updatable, view_definer, view_security_type) =
+            view_info;
 
-            db.set_table(table);
+        let mut view = View::new(view_name, view_definition);
+        view.set_updatable(view_updatable == "YES")
+            .set_check_option(view_check_option.filter(|c| c != "NONE"))
+            .set_definer(view_definer)
+            .set_security_type(view_security_type);
+
+        for col in self.get_column_reflections(view_name).await? {
+            view.set_column(col);
         }
 
-        //TODO InnoDB check
-        // SELECT TABLE_NAME, ENGINE FROM information_schema.TABLES WHERE TABLE_SCHEMA = 'exams';
+        Ok(view)
+    }
 
-        // collect constraints
-        let foreign_keys: Vec<TableConstraintTuple> = sqlx::query_as(
-            format!(
-                r#"
-        SELECT
-          CONSTRAINT_NAME, TABLE_NAME, COLUMN_NAME, REFERENCED_TABLE_NAME, REFERENCED_COLUMN_NAME
-        FROM
-          INFORMATION_SCHEMA.KEY_COLUMN_USAGE
-        WHERE
-          TABLE_SCHEMA = '{}' AND
-          CONSTRAINT_NAME != 'PRIMARY' AND
-          REFERENCED_TABLE_SCHEMA = TABLE_SCHEMA
-        ORDER BY CONSTRAINT_NAME ASC, POSITION_IN_UNIQUE_CONSTRAINT ASC
-        "#,
-                &self.database_name
+    async fn get_trigger_reflections(&self) -> Result<Vec<Trigger>, ReflectionAdapterError> {
+        let triggers: Vec<TriggerTuple> = self.guard(sqlx::query_as(&format!(
+            r#"
+            SELECT 
+                TRIGGER_NAME, 
+                EVENT_OBJECT_TABLE, 
+                ACTION_TIMING, 
+                EVENT_MANIPULATION, 
+                ACTION_STATEMENT, 
+                ACTION_ORDER, 
+                DEFINER 
+            FROM information_schema.`TRIGGERS` 
+            WHERE TRIGGER_SCHEMA = '{}' 
+            ORDER BY EVENT_OBJECT_TABLE ASC, ACTION_TIMING ASC, EVENT_MANIPULATION ASC, ACTION_ORDER ASC
+            "#,
+            &self.database_name
+        ))
+        .fetch_all(self.get_connection())).await?;
+
+        Ok(triggers
+            .into_iter()
+            .map(|trigger| {
+                let (
+                    trigger_name,
+                    trigger_table,
+                    trigger_timing,
+                    trigger_event,
+                    trigger_statement,
+                    trigger_order,
+                    trigger_definer,
+                ) = trigger;
+
+                Trigger::new(
+                    trigger_name,
+                    trigger_table,
+                    trigger_timing,
+                    trigger_event,
+                    trigger_statement,
+                )
+                .set_action_order(trigger_order)
+                .set_definer(trigger_definer)
+                .to_owned()
+            })
+            .collect())
+    }
+
+    async fn get_routine_reflections(&self) -> Result<Vec<Routine>, ReflectionAdapterError> {
+        let routines: Vec<RoutineTuple> = self
+            .guard(
+                sqlx::query_as(&format!(
+                    r#"
+            SELECT 
+                ROUTINE_NAME, 
+                ROUTINE_TYPE, 
+                DTD_IDENTIFIER, 
+                ROUTINE_DEFINITION, 
+                IS_DETERMINISTIC, 
+                SQL_DATA_ACCESS, 
+                SECURITY_TYPE, 
+                DEFINER, 
+                ROUTINE_COMMENT 
+            FROM information_schema.`ROUTINES` 
+            WHERE ROUTINE_SCHEMA = '{}' 
+            ORDER BY ROUTINE_NAME ASC
+            "#,
+                    &self.database_name
+                ))
+                .fetch_all(self.get_connection()),
             )
-            .as_str(),
-        )
-        .fetch_all(self.get_connection())
-        .await
-        .map_err(DatabaseError)?;
+            .await?;
 
-        for foreign_key in foreign_keys {
-            let (
-                fk_name,
-                local_table_name,
-                local_column_name,
-                foreign_table_name,
-                foreign_column_name,
-            ) = foreign_key;
-
-            if let Some(local_table) = db.table(&local_table_name) {
-                if let Some(local_column) = local_table.column(&local_column_name) {
-                    if let Some(foreign_table) =
-                        db.table(foreign_table_name.unwrap_or_default().as_str())
+        let parameters: Vec<RoutineParameterTuple> = self
+            .guard(
+                sqlx::query_as(&format!(
+                    r#"
+            SELECT 
+                SPECIFIC_NAME, 
+                ROUTINE_TYPE, 
+                PARAMETER_MODE, 
+                PARAMETER_NAME, 
+                DTD_IDENTIFIER 
+            FROM information_schema.`PARAMETERS` 
+            WHERE SPECIFIC_SCHEMA = '{}' 
+            AND ORDINAL_POSITION > 0 
+            ORDER BY SPECIFIC_NAME ASC, ORDINAL_POSITION ASC
+            "#,
+                    &self.database_name
+                ))
+                .fetch_all(self.get_connection()),
+            )
+            .await?;
+
+        Ok(routines
+            .into_iter()
+            .map(|routine| {
+                let (
+                    routine_name,
+                    routine_type,
+                    routine_returns,
+                    routine_definition,
+                    routine_deterministic,
+                    routine_data_access,
+                    routine_security_type,
+                    routine_definer,
+                    routine_comment,
+                ) = routine;
+
+                let mut r = Routine::new(
+                    &routine_name,
+                    if routine_type == "FUNCTION" {
+                        RoutineKind::Function
+                    } else {
+                        RoutineKind::Procedure
+                    },
+                );
+
+                r.set_declared_returns(routine_returns)
+                    .set_definition(routine_definition)
+                    .set_deterministic(routine_deterministic == "YES")
+                    .set_data_access(routine_data_access)
+                    .set_security_type(routine_security_type)
+                    .set_definer(routine_definer)
+                    .set_comment(routine_comment.filter(|c| !c.is_empty()));
+
+                for parameter in parameters
+                    .iter()
+                    .filter(|p| p.0 == routine_name && p.1 == routine_type)
+                {
+                    let (_, _, parameter_mode, parameter_name, parameter_type) = parameter;
+
+                    r.add_declared_parameter(
+                        parameter_name.clone().unwrap_or_default(),
+                        match parameter_mode.as_deref() {
+                            Some("IN") => Some(ParameterMode::In),
+                            Some("OUT") => Some(ParameterMode::Out),
+                            Some("INOUT") => Some(ParameterMode::InOut),
+                            _ => None,
+                        },
+                        parameter_type,
+                    );
+                }
+
+                r
+            })
+            .collect())
+    }
+
+    async fn get_event_reflections(&self) -> Result<Vec<Event>, ReflectionAdapterError> {
+        let events: Vec<EventTuple> = self
+            .guard(
+                sqlx::query_as(&format!(
+                    r#"
+            SELECT 
+                EVENT_NAME, 
+                EVENT_DEFINITION, 
+                EVENT_TYPE, 
+                CAST(EXECUTE_AT AS CHAR), 
+                INTERVAL_VALUE, 
+                INTERVAL_FIELD, 
+                CAST(STARTS AS CHAR), 
+                CAST(ENDS AS CHAR), 
+                STATUS, 
+                ON_COMPLETION, 
+                DEFINER, 
+                EVENT_COMMENT 
+            FROM information_schema.`EVENTS` 
+            WHERE EVENT_SCHEMA = '{}' 
+            ORDER BY EVENT_NAME ASC
+            "#,
+                    &self.database_name
+                ))
+                .fetch_all(self.get_connection()),
+            )
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .map(|event| {
+                let (
+                    event_name,
+                    event_definition,
+                    event_type,
+                    event_execute_at,
+                    event_interval_value,
+                    event_interval_field,
+                    event_starts,
+                    event_ends,
+                    event_status,
+                    event_on_completion,
+                    event_definer,
+                    event_comment,
+                ) = event;
+
+                Event::new(event_name, event_definition, event_type)
+                    .set_execute_at(event_execute_at)
+                    .set_interval(event_interval_value, event_interval_field)
+                    .set_schedule(event_starts, event_ends)
+                    .set_status(event_status)
+                    .set_on_completion(event_on_completion)
+                    .set_definer(event_definer)
+                    .set_comment(event_comment.filter(|c| !c.is_empty()))
+                    .to_owned()
+            })
+            .collect())
+    }
+
+    async fn get_reflection(&self) -> Result<Database, ReflectionAdapterError> {
+        self.clear_warnings();
+
+        Ok(self.get_database_reflection(None).await?.0)
+    }
+
+    async fn refresh_reflection(
+        &self,
+        previous: &Database,
+    ) -> Result<(Database, Vec<TableChange>), ReflectionAdapterError> {
+        self.clear_warnings();
+
+        self.get_database_reflection(Some(previous)).await
+    }
+
+    async fn get_catalog_reflection(
+        &self,
+        database_names: &[String],
+    ) -> Result<Catalog, ReflectionAdapterError> {
+        self.clear_warnings();
+
+        let mut databases: IndexMap<String, Database> = IndexMap::new();
+        for database_name in database_names {
+            let adapter = self.with_database_name(database_name);
+
+            databases.insert(
+                database_name.clone(),
+                adapter.get_database_reflection(None).await?.0,
+            );
+        }
+
+        if !databases.is_empty() {
+            let foreign_keys: Vec<CrossDatabaseConstraintTuple> = self.guard(sqlx::query_as(&format!(
+                r#"
+            SELECT
+              CONSTRAINT_NAME, TABLE_SCHEMA, TABLE_NAME, COLUMN_NAME, REFERENCED_TABLE_SCHEMA, REFERENCED_TABLE_NAME, REFERENCED_COLUMN_NAME
+            FROM
+              INFORMATION_SCHEMA.KEY_COLUMN_USAGE
+            WHERE
+              TABLE_SCHEMA IN ({}) AND
+              CONSTRAINT_NAME != 'PRIMARY' AND
+              REFERENCED_TABLE_SCHEMA IS NOT NULL AND
+              REFERENCED_TABLE_SCHEMA != TABLE_SCHEMA
+            ORDER BY TABLE_SCHEMA ASC, CONSTRAINT_NAME ASC, POSITION_IN_UNIQUE_CONSTRAINT ASC
+            "#,
+                databases
+                    .keys()
+                    .map(|name| format!("'{}'", name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))
+            .fetch_all(self.get_connection())).await?;
+
+            for foreign_key in foreign_keys {
+                let (
+                    fk_name,
+                    local_database_name,
+                    local_table_name,
+                    local_column_name,
+                    foreign_database_name,
+                    foreign_table_name,
+                    foreign_column_name,
+                ) = foreign_key;
+                let foreign_database_name = foreign_database_name.unwrap_or_default();
+
+                let foreign_column = databases
+                    .get(&foreign_database_name)
+                    .and_then(|db| db.table(foreign_table_name.as_deref().unwrap_or_default()))
+                    .and_then(|table| {
+                        table.column(foreign_column_name.as_deref().unwrap_or_default())
+                    });
+
+                let Some(foreign_column) = foreign_column else {
+                    self.warn(format!(
+                        "Constraint {}.{} references {}.{} which is not part of the reflection",
+                        local_database_name,
+                        fk_name,
+                        foreign_database_name,
+                        foreign_table_name.unwrap_or_default()
+                    ));
+
+                    continue;
+                };
+
+                if let Some(db) = databases.get_mut(&local_database_name) {
+                    if let Some(local_column) = db
+                        .table(&local_table_name)
+                        .and_then(|table| table.column(&local_column_name))
                     {
-                        if let Some(foreign_column) =
-                            foreign_table.column(foreign_column_name.unwrap_or_default().as_str())
-                        {
-                            let constraint = if let Some(constraint) = db.constraint(&fk_name) {
-                                let mut c = (*constraint).clone();
-                                c.add_key_pair(local_column, foreign_column);
-
-                                c
-                            } else {
-                                Constraint::new(fk_name, local_column, foreign_column)
-                            };
-
-                            db.set_constraint(constraint);
-                        }
+                        let constraint = if let Some(constraint) = db.constraint(&fk_name) {
+                            let mut c = (*constraint).clone();
+                            c.add_key_pair(local_column, foreign_column);
+
+                            c
+                        } else {
+                            Constraint::new(fk_name, local_column, foreign_column)
+                                .set_foreign_database(Some(foreign_database_name))
+                                .to_owned()
+                        };
+
+                        db.set_constraint(constraint);
                     }
                 }
             }
         }
 
-        Ok(db)
+        let mut catalog = Catalog::new();
+        for db in databases.into_values() {
+            catalog.set_database(db);
+        }
+
+        Ok(catalog)
+    }
+}
+
+/// Add foreign keys to the database, skipping those whose tables or columns are missing
+fn add_foreign_keys(db: &mut Database, foreign_keys: Vec<TableConstraintTuple>) {
+    for foreign_key in foreign_keys {
+        let (fk_name, local_table_name, local_column_name, foreign_table_name, foreign_column_name) =
+            foreign_key;
+
+        db.add_constraint_key_pair(
+            &fk_name,
+            (&local_table_name, &local_column_name),
+            (
+                foreign_table_name.as_deref().unwrap_or_default(),
+                foreign_column_name.as_deref().unwrap_or_default(),
+            ),
+        );
     }
 }
@@ -1,8 +1,15 @@
-use crate::reflection::{Database, Table};
+use crate::reflection::{Catalog, Database, Event, IntegrityIssue, Routine, Table, Trigger, View};
 use sqlx::{Error, Pool};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::future::Future;
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
 
 #[derive(Clone, Default, Debug)]
 pub struct Uninitialized<DB: sqlx::Database>(PhantomData<DB>);
@@ -38,6 +45,10 @@ pub enum ReflectionAdapterError {
     DatabaseError(Error),
     ValidationError(String),
     IntegrityError(String),
+    Cancelled,
+    Timeout(Duration),
+    /// Operation the adapter does not implement
+    Unsupported(String),
 }
 
 impl Display for ReflectionAdapterError {
@@ -49,14 +60,208 @@ impl Display for ReflectionAdapterError {
             ReflectionAdapterError::DatabaseError(e) => {
                 write!(f, "DatabaseError: {}", e)
             }
-            ReflectionAdapterError::ValidationError(e) => write!(f, "ConnectionError: {}", e),
-            ReflectionAdapterError::IntegrityError(e) => write!(f, "ConnectionError: {}", e),
+            ReflectionAdapterError::ValidationError(e) => write!(f, "ValidationError: {}", e),
+            ReflectionAdapterError::IntegrityError(e) => write!(f, "IntegrityError: {}", e),
+            ReflectionAdapterError::Cancelled => write!(f, "Cancelled"),
+            ReflectionAdapterError::Timeout(d) => {
+                write!(f, "Timeout: query took longer than {:?}", d)
+            }
+            ReflectionAdapterError::Unsupported(operation) => {
+                write!(
+                    f,
+                    "Unsupported: {} is not implemented by this adapter",
+                    operation
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for ReflectionAdapterError {}
 
+impl From<Vec<IntegrityIssue>> for ReflectionAdapterError {
+    fn from(issues: Vec<IntegrityIssue>) -> Self {
+        ReflectionAdapterError::IntegrityError(
+            issues
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>()
+                .join("; "),
+        )
+    }
+}
+
+/// Progress events emitted while reflecting
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReflectionEvent {
+    DatabaseStarted(String),
+    TableStarted(String),
+    TableFinished(String),
+    ViewStarted(String),
+    ViewFinished(String),
+    ConstraintsLoaded(usize),
+    DatabaseFinished(String),
+    Warning(String),
+}
+
+/// Callback receiving reflection progress events, called from whichever task is reflecting
+#[derive(Clone)]
+pub struct ReflectionObserver(Arc<dyn Fn(&ReflectionEvent) + Send + Sync>);
+
+impl ReflectionObserver {
+    pub fn new(callback: impl Fn(&ReflectionEvent) + Send + Sync + 'static) -> ReflectionObserver {
+        ReflectionObserver(Arc::new(callback))
+    }
+
+    /// Pass an event to the callback
+    pub fn notify(&self, event: &ReflectionEvent) {
+        (self.0)(event)
+    }
+}
+
+impl std::fmt::Debug for ReflectionObserver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReflectionObserver")
+    }
+}
+
+/// Table touched by an incremental refresh
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableChange {
+    Added(Arc<String>),
+    Modified(Arc<String>),
+    Dropped(Arc<String>),
+}
+
+impl TableChange {
+    /// Get name of the touched table
+    pub fn table_name(&self) -> Arc<String> {
+        match self {
+            TableChange::Added(name) | TableChange::Modified(name) | TableChange::Dropped(name) => {
+                name.clone()
+            }
+        }
+    }
+}
+
+#[derive(Default, Debug)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Create time, update time and definition checksum of a table as reported by the server
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TableStamp {
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    pub checksum: Option<String>,
+}
+
+impl TableStamp {
+    /// Check whether a previously reflected table carries this stamp
+    pub fn matches(&self, table: &Table) -> bool {
+        table.has_change_stamp()
+            && (table.create_time(), table.update_time(), table.checksum())
+                == (
+                    self.create_time.as_deref(),
+                    self.update_time.as_deref(),
+                    self.checksum.as_deref(),
+                )
+    }
+}
+
+/// Compare the tables currently on the server to a previous reflection, returning the
+/// changes and the names of the tables that can be reused as they are
+pub fn detect_table_changes(
+    previous: &Database,
+    table_names: &[String],
+    stamps: &HashMap<String, TableStamp>,
+) -> (Vec<TableChange>, Vec<String>) {
+    let mut changes = Vec::new();
+    let mut reusable = Vec::new();
+
+    for table_name in table_names {
+        match previous.table(table_name) {
+            Some(previous_table) => {
+                if stamps
+                    .get(table_name)
+                    .is_some_and(|stamp| stamp.matches(&previous_table))
+                {
+                    reusable.push(table_name.clone());
+                } else {
+                    changes.push(TableChange::Modified(Arc::new(table_name.clone())));
+                }
+            }
+            None => changes.push(TableChange::Added(Arc::new(table_name.clone()))),
+        }
+    }
+
+    for (table_name, _) in previous.tables() {
+        if !table_names.contains(table_name) {
+            changes.push(TableChange::Dropped(table_name.clone()));
+        }
+    }
+
+    (changes, reusable)
+}
+
+/// Shared flag to stop a running reflection, aborting the query in flight
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken(Arc<CancellationState>);
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Request cancellation
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+
+        if let Ok(mut wakers) = self.0.wakers.lock() {
+            wakers.drain(..).for_each(Waker::wake);
+        }
+    }
+
+    /// Check if cancellation was requested
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Wait until cancellation is requested
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled(self)
+    }
+}
+
+/// Future resolving once its token is cancelled
+#[derive(Debug)]
+pub struct Cancelled<'a>(&'a CancellationToken);
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        if let Ok(mut wakers) = self.0 .0.wakers.lock() {
+            if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+
+        // cancel() may have run before the waker was registered
+        if self.0.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 pub trait ReflectionAdapterUninitialized<T: sqlx::Database> {
     type ValidAdapter: ReflectionAdapter<T>;
 
@@ -69,6 +274,16 @@ pub trait ReflectionAdapterUninitialized<T: sqlx::Database> {
 
 pub trait ReflectionAdapter<T: sqlx::Database> {
     type InvalidAdapter: ReflectionAdapterUninitialized<T>;
+
+    /// Set progress observer, ignored unless the adapter reports progress
+    fn set_observer(&mut self, _observer: Option<ReflectionObserver>) {}
+
+    /// Set cancellation token, ignored unless the adapter supports cancellation
+    fn set_cancellation_token(&mut self, _token: Option<CancellationToken>) {}
+
+    /// Set per-query timeout, ignored unless the adapter supports timeouts
+    fn set_query_timeout(&mut self, _timeout: Option<Duration>) {}
+
     fn disconnect(
         self,
     ) -> impl std::future::Future<Output = Result<Self::InvalidAdapter, ReflectionAdapterError>> + Send;
@@ -88,12 +303,89 @@ pub trait ReflectionAdapter<T: sqlx::Database> {
         &self,
     ) -> impl std::future::Future<Output = Result<Vec<String>, ReflectionAdapterError>> + Send;
 
+    fn list_view_names(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<String>, ReflectionAdapterError>> + Send {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "list_view_names".to_string(),
+            ))
+        }
+    }
+
     fn get_table_reflection(
         &self,
         table_name: &str,
     ) -> impl std::future::Future<Output = Result<Table, ReflectionAdapterError>> + Send;
 
+    fn get_view_reflection(
+        &self,
+        _view_name: &str,
+    ) -> impl std::future::Future<Output = Result<View, ReflectionAdapterError>> + Send {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "get_view_reflection".to_string(),
+            ))
+        }
+    }
+
+    fn get_trigger_reflections(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<Trigger>, ReflectionAdapterError>> + Send
+    {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "get_trigger_reflections".to_string(),
+            ))
+        }
+    }
+
+    fn get_routine_reflections(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<Routine>, ReflectionAdapterError>> + Send
+    {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "get_routine_reflections".to_string(),
+            ))
+        }
+    }
+
+    fn get_event_reflections(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<Event>, ReflectionAdapterError>> + Send {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "get_event_reflections".to_string(),
+            ))
+        }
+    }
+
     fn get_reflection(
         &self,
     ) -> impl std::future::Future<Output = Result<Database, ReflectionAdapterError>> + Send;
+
+    fn refresh_reflection(
+        &self,
+        _previous: &Database,
+    ) -> impl std::future::Future<
+        Output = Result<(Database, Vec<TableChange>), ReflectionAdapterError>,
+    > + Send {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "refresh_reflection".to_string(),
+            ))
+        }
+    }
+
+    fn get_catalog_reflection(
+        &self,
+        _database_names: &[String],
+    ) -> impl std::future::Future<Output = Result<Catalog, ReflectionAdapterError>> + Send {
+        async {
+            Err(ReflectionAdapterError::Unsupported(
+                "get_catalog_reflection".to_string(),
+            ))
+        }
+    }
 }
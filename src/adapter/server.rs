@@ -0,0 +1,217 @@
+use crate::reflection::SqlDatatype;
+use std::fmt::{Display, Formatter};
+
+/// Database server product behind a MySQL protocol connection
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum ServerFlavor {
+    #[default]
+    MariaDb,
+    MySql,
+}
+
+impl Display for ServerFlavor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServerFlavor::MariaDb => write!(f, "MariaDB"),
+            ServerFlavor::MySql => write!(f, "MySQL"),
+        }
+    }
+}
+
+/// Server flavor and version detected on connect
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ServerInfo {
+    flavor: ServerFlavor,
+    version: String,
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl ServerInfo {
+    /// Parse the result of `SELECT VERSION()`, i.e. `10.11.6-MariaDB-log` or `8.0.36`
+    pub fn parse(version: &str) -> ServerInfo {
+        let flavor = if version.to_lowercase().contains("mariadb") {
+            ServerFlavor::MariaDb
+        } else {
+            ServerFlavor::MySql
+        };
+
+        // old MariaDB releases prefix the version for replication compatibility
+        let numbers = version.strip_prefix("5.5.5-").unwrap_or(version);
+        let mut parts = numbers
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|p| p.parse::<u32>().unwrap_or_default());
+
+        ServerInfo {
+            flavor,
+            version: version.to_string(),
+            major: parts.next().unwrap_or_default(),
+            minor: parts.next().unwrap_or_default(),
+            patch: parts.next().unwrap_or_default(),
+        }
+    }
+
+    /// Get server flavor
+    pub fn flavor(&self) -> ServerFlavor {
+        self.flavor
+    }
+
+    /// Get version string as reported by the server
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Get major, minor and patch version
+    pub fn version_numbers(&self) -> (u32, u32, u32) {
+        (self.major, self.minor, self.patch)
+    }
+
+    /// Check whether the server is MySQL
+    pub fn is_mysql(&self) -> bool {
+        self.flavor == ServerFlavor::MySql
+    }
+
+    /// Check whether the server is MariaDB
+    pub fn is_mariadb(&self) -> bool {
+        self.flavor == ServerFlavor::MariaDb
+    }
+
+    /// Check whether the server version is at least the given one
+    pub fn at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        (self.major, self.minor, self.patch) >= (major, minor, patch)
+    }
+
+    /// Check whether column defaults are reported as SQL expressions, quoted literals
+    /// included, which MariaDB does since 10.2.7
+    fn quotes_defaults(&self) -> bool {
+        self.is_mariadb() && self.at_least(10, 2, 7)
+    }
+
+    /// Rewrite a `SHOW FULL COLUMNS` extra into the lowercase MariaDB form,
+    /// MySQL and MariaDB before 10.2.7 omit the parentheses of `CURRENT_TIMESTAMP`,
+    /// MySQL adds `DEFAULT_GENERATED`
+    pub fn normalize_extra(&self, extra: &str) -> String {
+        let extra = extra.to_lowercase();
+
+        if self.quotes_defaults() {
+            return extra;
+        }
+
+        normalize_current_timestamp(
+            &extra
+                .split_whitespace()
+                .filter(|e| *e != "default_generated")
+                .collect::<Vec<&str>>()
+                .join(" "),
+        )
+    }
+
+    /// Rewrite an information_schema column default into the MariaDB form.
+    /// MySQL and MariaDB before 10.2.7 report literals unquoted and leave the implicit
+    /// `DEFAULT NULL` of nullable columns empty, MySQL marks expressions `DEFAULT_GENERATED`.
+    pub fn normalize_default(
+        &self,
+        default: Option<&str>,
+        extra: &str,
+        nullable: bool,
+        datatype: &SqlDatatype,
+    ) -> Option<String> {
+        if self.quotes_defaults() {
+            return default.map(|d| d.to_string());
+        }
+
+        let extra = extra.to_lowercase();
+
+        let Some(default) = default else {
+            let implicit_null =
+                nullable && !extra.contains("generated") && !extra.contains("auto_increment");
+
+            return implicit_null.then(|| "NULL".to_string());
+        };
+
+        if extra.contains("default_generated")
+            || default.to_lowercase().starts_with("current_timestamp")
+        {
+            return Some(normalize_current_timestamp(default));
+        }
+
+        let numeric = !datatype.is_text()
+            && default.parse::<f64>().is_ok()
+            && !matches!(datatype, SqlDatatype::Enum(_) | SqlDatatype::Set(_));
+
+        if numeric || default.starts_with("b'") || default.starts_with("0x") {
+            Some(default.to_string())
+        } else {
+            Some(format!("'{}'", default.replace('\'', "''")))
+        }
+    }
+
+    /// Rewrite a check clause into the MariaDB form, MySQL wraps it in parentheses
+    pub fn normalize_check_clause(&self, clause: &str) -> String {
+        let clause = clause.trim();
+
+        if self.is_mysql() && wrapped_in_parentheses(clause) {
+            clause[1..clause.len() - 1].trim().to_string()
+        } else {
+            clause.to_string()
+        }
+    }
+}
+
+impl Display for ServerInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}.{}.{}",
+            self.flavor, self.major, self.minor, self.patch
+        )
+    }
+}
+
+/// Lowercase `current_timestamp` and add the empty parentheses MariaDB reports
+fn normalize_current_timestamp(value: &str) -> String {
+    let lowercase = value.to_lowercase();
+    let Some(rest) = lowercase.strip_prefix("current_timestamp") else {
+        return match lowercase.split_once(" current_timestamp") {
+            Some((head, tail)) => format!(
+                "{} {}",
+                head,
+                normalize_current_timestamp(&format!("current_timestamp{}", tail))
+            ),
+            None => value.to_string(),
+        };
+    };
+
+    if rest.starts_with('(') {
+        lowercase
+    } else {
+        format!("current_timestamp(){}", rest)
+    }
+}
+
+/// Check whether the outer parentheses enclose the whole expression
+fn wrapped_in_parentheses(value: &str) -> bool {
+    if !value.starts_with('(') || !value.ends_with(')') {
+        return false;
+    }
+
+    let mut depth = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 && i < value.len() - 1 {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    depth == 0
+}
@@ -0,0 +1,336 @@
+pub mod types;
+
+use crate::metadata::consts::{METADATA_ON_DELETE, METADATA_ON_UPDATE};
+use crate::metadata::WithMetadata;
+use crate::reflection::{
+    Column, Constraint, Database, DefaultValue, IntegrityIssue, SqlDatatype, Table,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Foreign key declared on a column, resolved by name when the database is built
+#[derive(Clone, Debug)]
+struct ForeignKeyDeclaration {
+    name: String,
+    table: String,
+    column: String,
+    target: String,
+    metadata: Vec<(String, String)>,
+}
+
+/// Index declared on a column, resolved by name when the table is built
+#[derive(Clone, Debug)]
+struct IndexDeclaration {
+    name: String,
+    column: String,
+    primary: bool,
+    unique: bool,
+}
+
+/// Fluent builder declaring a table, column modifiers apply to the most recently added column
+#[derive(Clone, Debug)]
+pub struct TableBuilder {
+    table: Table,
+    columns: Vec<Column>,
+    indexes: Vec<IndexDeclaration>,
+    foreign_keys: Vec<ForeignKeyDeclaration>,
+}
+
+impl TableBuilder {
+    /// Start declaring a table
+    pub fn new(name: impl ToString) -> TableBuilder {
+        TableBuilder {
+            table: Table::new(name),
+            columns: Vec::new(),
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    /// Set table charset and collation, inherited by text columns
+    pub fn charset(mut self, charset: impl ToString, collation: impl ToString) -> TableBuilder {
+        self.table
+            .set_charset(Some(charset))
+            .set_collation(Some(collation));
+        self
+    }
+
+    /// Set table comment
+    pub fn table_comment(mut self, comment: impl ToString) -> TableBuilder {
+        self.table.set_comment(Some(comment));
+        self
+    }
+
+    /// Set storage engine
+    pub fn engine(mut self, engine: impl ToString) -> TableBuilder {
+        self.table.set_engine(Some(engine));
+        self
+    }
+
+    /// Add a column
+    pub fn column(mut self, name: impl ToString, datatype: SqlDatatype) -> TableBuilder {
+        self.columns
+            .push(Column::new(self.table.name(), name, datatype));
+        self
+    }
+
+    /// Mark the column as part of the primary key
+    pub fn primary(mut self) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_primary(true);
+
+            self.indexes.push(IndexDeclaration {
+                name: String::from("PRIMARY"),
+                column: column.name().to_string(),
+                primary: true,
+                unique: true,
+            });
+        }
+        self
+    }
+
+    /// Mark the column as unique, adding a unique index named after it
+    pub fn unique(mut self) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_unique(true);
+
+            self.indexes.push(IndexDeclaration {
+                name: column.name().to_string(),
+                column: column.name().to_string(),
+                primary: false,
+                unique: true,
+            });
+        }
+        self
+    }
+
+    /// Add a non unique index on the column, repeating the name on further columns widens it
+    pub fn index(mut self, name: impl ToString) -> TableBuilder {
+        if let Some(column) = self.columns.last() {
+            self.indexes.push(IndexDeclaration {
+                name: name.to_string(),
+                column: column.name().to_string(),
+                primary: false,
+                unique: false,
+            });
+        }
+        self
+    }
+
+    /// Mark the column as nullable
+    pub fn nullable(mut self) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_nullable(true);
+        }
+        self
+    }
+
+    /// Mark the column as AUTO_INCREMENT
+    pub fn auto_increment(mut self) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_auto_increment(true);
+        }
+        self
+    }
+
+    /// Set column default value
+    pub fn default(mut self, value: DefaultValue) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_default(Some(value));
+        }
+        self
+    }
+
+    /// Set column comment
+    pub fn comment(mut self, comment: impl ToString) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_comment(Some(comment));
+        }
+        self
+    }
+
+    /// Set column metadata
+    pub fn meta(mut self, key: impl ToString, value: impl ToString) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_meta(key, value);
+        }
+        self
+    }
+
+    /// Set column metadata flag
+    pub fn meta_flag(mut self, flag: impl ToString) -> TableBuilder {
+        if let Some(column) = self.columns.last_mut() {
+            column.set_meta_flag(flag);
+        }
+        self
+    }
+
+    /// Reference a column of another table as `table.column`, naming the constraint `fk_<table>_<n>`
+    pub fn references(self, target: impl ToString) -> TableBuilder {
+        let mut names = self
+            .foreign_keys
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<&str>>();
+        names.sort();
+        names.dedup();
+        let name = format!("fk_{}_{}", self.table.name(), names.len() + 1);
+
+        self.references_as(name, target)
+    }
+
+    /// Reference a column of another table as `table.column` through a named constraint,
+    /// reusing a name adds another column pair to the same constraint
+    pub fn references_as(mut self, name: impl ToString, target: impl ToString) -> TableBuilder {
+        if let Some(column) = self.columns.last() {
+            self.foreign_keys.push(ForeignKeyDeclaration {
+                name: name.to_string(),
+                table: self.table.name().to_string(),
+                column: column.name().to_string(),
+                target: target.to_string(),
+                metadata: Vec::new(),
+            });
+        }
+        self
+    }
+
+    /// Set ON DELETE action of the last reference
+    pub fn on_delete(self, action: impl ToString) -> TableBuilder {
+        self.reference_meta(METADATA_ON_DELETE, action)
+    }
+
+    /// Set ON UPDATE action of the last reference
+    pub fn on_update(self, action: impl ToString) -> TableBuilder {
+        self.reference_meta(METADATA_ON_UPDATE, action)
+    }
+
+    fn reference_meta(mut self, key: impl ToString, value: impl ToString) -> TableBuilder {
+        if let Some(foreign_key) = self.foreign_keys.last_mut() {
+            foreign_key
+                .metadata
+                .push((key.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    /// Build the table with its columns and indexes, references are resolved by `DatabaseBuilder`
+    pub fn build(self) -> Table {
+        let mut table = self.table;
+
+        for column in self.columns {
+            table.set_column(column);
+        }
+
+        let mut sequences: HashMap<String, usize> = HashMap::new();
+        for index in self.indexes {
+            if let Some(column) = table.column(&index.column) {
+                let sequence = sequences.entry(index.name.clone()).or_default();
+                *sequence += 1;
+
+                table.add_index_column(index.name, column, *sequence, index.primary, index.unique);
+            }
+        }
+
+        table
+    }
+}
+
+/// Fluent builder declaring a database, resolving references between its tables by name
+#[derive(Clone, Debug)]
+pub struct DatabaseBuilder {
+    database: Database,
+    tables: Vec<TableBuilder>,
+}
+
+impl DatabaseBuilder {
+    /// Start declaring a database
+    pub fn new(name: impl ToString) -> DatabaseBuilder {
+        DatabaseBuilder {
+            database: Database::new(name),
+            tables: Vec::new(),
+        }
+    }
+
+    /// Set database charset and collation, inherited by tables without their own
+    pub fn charset(mut self, charset: impl ToString, collation: impl ToString) -> DatabaseBuilder {
+        self.database
+            .set_charset(Some(charset))
+            .set_collation(Some(collation));
+        self
+    }
+
+    /// Set database metadata
+    pub fn meta(mut self, key: impl ToString, value: impl ToString) -> DatabaseBuilder {
+        self.database.set_meta(key, value);
+        self
+    }
+
+    /// Declare a table
+    pub fn table(
+        mut self,
+        name: impl ToString,
+        declare: impl FnOnce(TableBuilder) -> TableBuilder,
+    ) -> DatabaseBuilder {
+        self.tables.push(declare(TableBuilder::new(name)));
+        self
+    }
+
+    /// Build the database, resolving references and validating the result
+    pub fn build(self) -> Result<Database, Vec<IntegrityIssue>> {
+        let mut db = self.database;
+        let mut foreign_keys = Vec::new();
+
+        for table in self.tables {
+            foreign_keys.extend(table.foreign_keys.iter().cloned());
+            db.set_table(table.build());
+        }
+
+        let mut issues = Vec::new();
+        for foreign_key in foreign_keys {
+            let (foreign_table, foreign_column) = foreign_key
+                .target
+                .split_once('.')
+                .unwrap_or((foreign_key.target.as_str(), ""));
+
+            let local = db
+                .table(&foreign_key.table)
+                .and_then(|t| t.column(&foreign_key.column));
+            let foreign = db
+                .table(foreign_table)
+                .and_then(|t| t.column(foreign_column));
+
+            let Some((local, foreign)) = local.zip(foreign) else {
+                issues.push(IntegrityIssue::DanglingConstraint {
+                    constraint: Arc::new(foreign_key.name),
+                    table: Arc::new(foreign_table.to_string()),
+                    column: Arc::new(foreign_column.to_string()),
+                });
+                continue;
+            };
+
+            let mut constraint = if let Some(constraint) = db.constraint(&foreign_key.name) {
+                let mut c = (*constraint).clone();
+                c.add_key_pair(local, foreign);
+
+                c
+            } else {
+                Constraint::new(foreign_key.name, local, foreign)
+            };
+            for (key, value) in foreign_key.metadata {
+                constraint.set_meta(key, value);
+            }
+
+            db.set_constraint(constraint);
+        }
+
+        if let Err(validation_issues) = db.validate() {
+            issues.extend(validation_issues);
+        }
+
+        if issues.is_empty() {
+            Ok(db)
+        } else {
+            Err(issues)
+        }
+    }
+}
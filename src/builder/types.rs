@@ -0,0 +1,80 @@
+//! Shorthand datatype constructors for the schema builder, numeric types are signed
+//! until `SqlDatatype::unsigned` is called on them
+
+use crate::reflection::{SqlDatatype, SqlSigned};
+
+pub fn tinyint(len: u32) -> SqlDatatype {
+    SqlDatatype::Tinyint(len, SqlSigned::Signed)
+}
+
+pub fn smallint(len: u32) -> SqlDatatype {
+    SqlDatatype::Smallint(len, SqlSigned::Signed)
+}
+
+pub fn mediumint(len: u32) -> SqlDatatype {
+    SqlDatatype::Mediumint(len, SqlSigned::Signed)
+}
+
+pub fn int(len: u32) -> SqlDatatype {
+    SqlDatatype::Int(len, SqlSigned::Signed)
+}
+
+pub fn bigint(len: u32) -> SqlDatatype {
+    SqlDatatype::Bigint(len, SqlSigned::Signed)
+}
+
+pub fn float(len: u32, scale: u32) -> SqlDatatype {
+    SqlDatatype::Float(len, scale, SqlSigned::Signed)
+}
+
+pub fn double(len: u32, scale: u32) -> SqlDatatype {
+    SqlDatatype::Double(len, scale, SqlSigned::Signed)
+}
+
+pub fn decimal(len: u32, scale: u32) -> SqlDatatype {
+    SqlDatatype::Decimal(len, scale, SqlSigned::Signed)
+}
+
+pub fn date() -> SqlDatatype {
+    SqlDatatype::Date
+}
+
+pub fn time() -> SqlDatatype {
+    SqlDatatype::Time
+}
+
+pub fn datetime() -> SqlDatatype {
+    SqlDatatype::Datetime
+}
+
+pub fn timestamp() -> SqlDatatype {
+    SqlDatatype::Timestamp
+}
+
+pub fn char(len: u32) -> SqlDatatype {
+    SqlDatatype::Char(len)
+}
+
+pub fn varchar(len: u32) -> SqlDatatype {
+    SqlDatatype::Varchar(len)
+}
+
+pub fn text(len: u32) -> SqlDatatype {
+    SqlDatatype::Text(len)
+}
+
+pub fn binary(len: u32) -> SqlDatatype {
+    SqlDatatype::Binary(len)
+}
+
+pub fn varbinary(len: u32) -> SqlDatatype {
+    SqlDatatype::Varbinary(len)
+}
+
+pub fn enumeration(options: &[&str]) -> SqlDatatype {
+    SqlDatatype::Enum(options.iter().map(|o| o.to_string()).collect())
+}
+
+pub fn set(options: &[&str]) -> SqlDatatype {
+    SqlDatatype::Set(options.iter().map(|o| o.to_string()).collect())
+}
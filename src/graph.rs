@@ -0,0 +1,7 @@
+mod dependency_graph;
+mod join_path;
+mod relationship;
+
+pub use crate::graph::dependency_graph::{DependencyCycle, DependencyEdge, DependencyGraph};
+pub use crate::graph::join_path::{JoinPath, JoinStep};
+pub use crate::graph::relationship::{Relationship, RelationshipKind, Relationships};
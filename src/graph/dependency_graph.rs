@@ -0,0 +1,266 @@
+use crate::reflection::{ConstraintSide, Database};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+/// Foreign key edge from a dependent table to the table it references
+#[derive(Clone, Debug, PartialEq)]
+pub struct DependencyEdge {
+    pub constraint: Arc<String>,
+    pub table: Arc<String>,
+    pub depends_on: Arc<String>,
+}
+
+/// Tables that reference each other in a loop, along with the constraints forming it
+#[derive(Clone, Debug, PartialEq)]
+pub struct DependencyCycle {
+    pub tables: Vec<Arc<String>>,
+    pub constraints: Vec<Arc<String>>,
+}
+
+/// Graph of foreign key dependencies between the tables of a database
+#[derive(Clone, Debug, Default)]
+pub struct DependencyGraph {
+    tables: Vec<Arc<String>>,
+    edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    /// Build the graph from the constraints of a database, ignoring cross database constraints
+    pub fn new(db: &Database) -> DependencyGraph {
+        let tables = db
+            .tables()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        let mut constraints = db.constraints().map(|(_, c)| c).collect::<Vec<_>>();
+        constraints.sort_by_key(|c| c.name());
+
+        let edges = constraints
+            .into_iter()
+            .filter(|c| !c.is_cross_database())
+            .filter(|c| {
+                tables.contains(&c.local().table()) && tables.contains(&c.foreign().table())
+            })
+            .map(|c| DependencyEdge {
+                constraint: c.name(),
+                table: c.local().table(),
+                depends_on: c.foreign().table(),
+            })
+            .collect();
+
+        DependencyGraph { tables, edges }
+    }
+
+    /// Get edges iterator
+    pub fn edges(&self) -> std::slice::Iter<'_, DependencyEdge> {
+        self.edges.iter()
+    }
+
+    /// Get tables directly referenced by a table
+    pub fn dependencies(&self, table: &str) -> Vec<Arc<String>> {
+        self.neighbours(table, ConstraintSide::Local)
+    }
+
+    /// Get tables directly referencing a table
+    pub fn dependents(&self, table: &str) -> Vec<Arc<String>> {
+        self.neighbours(table, ConstraintSide::Foreign)
+    }
+
+    /// Get tables reachable from a table, following references for the local side,
+    /// referencing tables for the foreign side, or both when no side is given
+    pub fn reachable_from(&self, table: &str, side: Option<ConstraintSide>) -> Vec<Arc<String>> {
+        let mut visited: HashSet<Arc<String>> = HashSet::new();
+        let mut queue = VecDeque::from([Arc::new(table.to_string())]);
+
+        while let Some(current) = queue.pop_front() {
+            let mut next = Vec::new();
+            if side != Some(ConstraintSide::Foreign) {
+                next.extend(self.dependencies(&current));
+            }
+            if side != Some(ConstraintSide::Local) {
+                next.extend(self.dependents(&current));
+            }
+
+            for neighbour in next {
+                if neighbour.as_str() != table && visited.insert(neighbour.clone()) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        self.tables
+            .iter()
+            .filter(|t| visited.contains(*t))
+            .cloned()
+            .collect()
+    }
+
+    /// Get tables ordered so that referenced tables come before the tables referencing them,
+    /// i.e. create or insert order; self references are ignored, other cycles are returned as errors
+    pub fn topological_order(&self) -> Result<Vec<Arc<String>>, Vec<DependencyCycle>> {
+        let mut pending: HashMap<Arc<String>, usize> = self
+            .tables
+            .iter()
+            .map(|t| {
+                (
+                    t.clone(),
+                    self.dependencies(t).iter().filter(|d| *d != t).count(),
+                )
+            })
+            .collect();
+        let mut order = Vec::with_capacity(self.tables.len());
+
+        while order.len() < self.tables.len() {
+            let ready = self
+                .tables
+                .iter()
+                .filter(|t| pending.get(*t) == Some(&0))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if ready.is_empty() {
+                return Err(self
+                    .cycles()
+                    .into_iter()
+                    .filter(|c| c.tables.len() > 1)
+                    .collect());
+            }
+
+            for table in ready {
+                pending.remove(&table);
+                for dependent in self.dependents(&table) {
+                    if dependent != table {
+                        if let Some(count) = pending.get_mut(&dependent) {
+                            *count -= 1;
+                        }
+                    }
+                }
+                order.push(table);
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Get tables ordered so that referencing tables come first, i.e. delete or truncate order
+    pub fn reverse_order(&self) -> Result<Vec<Arc<String>>, Vec<DependencyCycle>> {
+        self.topological_order().map(|mut order| {
+            order.reverse();
+            order
+        })
+    }
+
+    /// Find every cycle, including tables referencing themselves
+    pub fn cycles(&self) -> Vec<DependencyCycle> {
+        let mut cycles = Vec::new();
+
+        for component in self.strongly_connected_components() {
+            let constraints = self
+                .edges
+                .iter()
+                .filter(|e| component.contains(&e.table) && component.contains(&e.depends_on))
+                .map(|e| e.constraint.clone())
+                .collect::<Vec<_>>();
+
+            if component.len() > 1 || !constraints.is_empty() {
+                cycles.push(DependencyCycle {
+                    tables: component,
+                    constraints,
+                });
+            }
+        }
+
+        cycles
+    }
+
+    /// Check if any tables reference each other in a loop, including self references
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles().is_empty()
+    }
+
+    fn neighbours(&self, table: &str, side: ConstraintSide) -> Vec<Arc<String>> {
+        let mut neighbours = Vec::new();
+
+        for edge in self.edges.iter() {
+            let (from, to) = match side {
+                ConstraintSide::Local => (&edge.table, &edge.depends_on),
+                ConstraintSide::Foreign => (&edge.depends_on, &edge.table),
+            };
+
+            if from.as_str() == table && !neighbours.contains(to) {
+                neighbours.push(to.clone());
+            }
+        }
+
+        neighbours
+    }
+
+    /// Tarjan's algorithm, components keep the table order of the database
+    fn strongly_connected_components(&self) -> Vec<Vec<Arc<String>>> {
+        struct Search<'a> {
+            graph: &'a DependencyGraph,
+            counter: usize,
+            index: HashMap<Arc<String>, usize>,
+            low_link: HashMap<Arc<String>, usize>,
+            stack: Vec<Arc<String>>,
+            components: Vec<Vec<Arc<String>>>,
+        }
+
+        impl Search<'_> {
+            fn visit(&mut self, table: &Arc<String>) {
+                self.index.insert(table.clone(), self.counter);
+                self.low_link.insert(table.clone(), self.counter);
+                self.counter += 1;
+                self.stack.push(table.clone());
+
+                for dependency in self.graph.dependencies(table) {
+                    if !self.index.contains_key(&dependency) {
+                        self.visit(&dependency);
+                        let low = self.low_link[&dependency].min(self.low_link[table]);
+                        self.low_link.insert(table.clone(), low);
+                    } else if self.stack.contains(&dependency) {
+                        let low = self.index[&dependency].min(self.low_link[table]);
+                        self.low_link.insert(table.clone(), low);
+                    }
+                }
+
+                if self.low_link[table] == self.index[table] {
+                    let mut component = Vec::new();
+                    while let Some(member) = self.stack.pop() {
+                        let done = member == *table;
+                        component.push(member);
+                        if done {
+                            break;
+                        }
+                    }
+
+                    self.components.push(
+                        self.graph
+                            .tables
+                            .iter()
+                            .filter(|t| component.contains(t))
+                            .cloned()
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        let mut search = Search {
+            graph: self,
+            counter: 0,
+            index: HashMap::new(),
+            low_link: HashMap::new(),
+            stack: Vec::new(),
+            components: Vec::new(),
+        };
+
+        for table in self.tables.iter() {
+            if !search.index.contains_key(table) {
+                search.visit(table);
+            }
+        }
+
+        search.components
+    }
+}
@@ -0,0 +1,161 @@
+use crate::reflection::{Constraint, ConstraintKeyPair, ConstraintSide, Database};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+/// One join between two tables over a foreign key
+#[derive(Clone, Debug)]
+pub struct JoinStep {
+    pub constraint: Arc<String>,
+    pub from_table: Arc<String>,
+    pub to_table: Arc<String>,
+    /// Local when following the foreign key from the referencing table, Foreign when going back from the referenced one
+    pub direction: ConstraintSide,
+    pub key_pairs: Vec<ConstraintKeyPair>,
+}
+
+impl JoinStep {
+    /// Render as `JOIN ... ON ...`
+    pub fn to_sql(&self) -> String {
+        let conditions = self
+            .key_pairs
+            .iter()
+            .map(|pair| {
+                let (from, to) = match self.direction {
+                    ConstraintSide::Local => (&pair.local, &pair.foreign),
+                    ConstraintSide::Foreign => (&pair.foreign, &pair.local),
+                };
+
+                format!(
+                    "`{}`.`{}` = `{}`.`{}`",
+                    to.table(),
+                    to.name(),
+                    from.table(),
+                    from.name()
+                )
+            })
+            .collect::<Vec<String>>();
+
+        format!("JOIN `{}` ON {}", self.to_table, conditions.join(" AND "))
+    }
+}
+
+/// Chain of joins leading from one table to another
+#[derive(Clone, Debug, Default)]
+pub struct JoinPath {
+    steps: Vec<JoinStep>,
+}
+
+impl JoinPath {
+    /// Find the shortest chains of same database constraints connecting two tables,
+    /// several when they are equally short
+    pub fn find(db: &Database, from: &str, to: &str) -> Vec<JoinPath> {
+        if db.table(from).is_none() || db.table(to).is_none() {
+            return Vec::new();
+        }
+        if from == to {
+            return vec![JoinPath::default()];
+        }
+
+        let mut constraints = db
+            .constraints()
+            .map(|(_, c)| c.clone())
+            .filter(|c| !c.is_cross_database() && c.local().table() != c.foreign().table())
+            .collect::<Vec<Arc<Constraint>>>();
+        constraints.sort_by_key(|c| c.name());
+
+        // breadth first, remembering every step that reaches a table at its shortest distance
+        let mut distance: HashMap<Arc<String>, usize> = HashMap::new();
+        let mut arrivals: HashMap<Arc<String>, Vec<JoinStep>> = HashMap::new();
+        let mut queue = VecDeque::from([Arc::new(from.to_string())]);
+        distance.insert(Arc::new(from.to_string()), 0);
+
+        let target = Arc::new(to.to_string());
+        while let Some(table) = queue.pop_front() {
+            if table == target {
+                break;
+            }
+            let next_distance = distance[&table] + 1;
+
+            for constraint in constraints.iter() {
+                let direction = if constraint.local().table() == table {
+                    ConstraintSide::Local
+                } else if constraint.foreign().table() == table {
+                    ConstraintSide::Foreign
+                } else {
+                    continue;
+                };
+                let next = match direction {
+                    ConstraintSide::Local => constraint.foreign().table(),
+                    ConstraintSide::Foreign => constraint.local().table(),
+                };
+
+                match distance.get(&next) {
+                    Some(d) if *d < next_distance => continue,
+                    Some(_) => {}
+                    None => {
+                        distance.insert(next.clone(), next_distance);
+                        queue.push_back(next.clone());
+                    }
+                }
+
+                arrivals.entry(next.clone()).or_default().push(JoinStep {
+                    constraint: constraint.name(),
+                    from_table: table.clone(),
+                    to_table: next,
+                    direction,
+                    key_pairs: constraint.key_pairs().cloned().collect(),
+                });
+            }
+        }
+
+        let mut paths = Vec::new();
+        Self::collect(&arrivals, from, target, Vec::new(), &mut paths);
+
+        paths
+    }
+
+    fn collect(
+        arrivals: &HashMap<Arc<String>, Vec<JoinStep>>,
+        from: &str,
+        table: Arc<String>,
+        tail: Vec<JoinStep>,
+        paths: &mut Vec<JoinPath>,
+    ) {
+        if table.as_str() == from {
+            let mut steps = tail;
+            steps.reverse();
+            paths.push(JoinPath { steps });
+            return;
+        }
+
+        for step in arrivals.get(&table).into_iter().flatten() {
+            let mut tail = tail.clone();
+            tail.push(step.clone());
+            Self::collect(arrivals, from, step.from_table.clone(), tail, paths);
+        }
+    }
+
+    /// Get steps iterator
+    pub fn steps(&self) -> std::slice::Iter<'_, JoinStep> {
+        self.steps.iter()
+    }
+
+    /// Get number of joins
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Check if no joins are needed, i.e. both tables are the same
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Render as consecutive `JOIN ... ON ...` clauses
+    pub fn to_sql(&self) -> String {
+        self.steps
+            .iter()
+            .map(|s| s.to_sql())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
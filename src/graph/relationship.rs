@@ -0,0 +1,225 @@
+use crate::reflection::{Constraint, Database, Table};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipKind {
+    OneToOne,
+    OneToMany,
+    ManyToOne,
+    ManyToMany,
+}
+
+/// Relationship seen from `table` towards `related_table`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    pub name: String,
+    pub kind: RelationshipKind,
+    pub table: Arc<String>,
+    pub related_table: Arc<String>,
+    /// Constraints followed from `table` to `related_table`, two for many-to-many
+    pub constraints: Vec<Arc<String>>,
+    /// Junction table of a many-to-many relationship
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub through: Option<Arc<String>>,
+    /// Whether the related side may be missing, i.e. the foreign key is nullable
+    pub optional: bool,
+}
+
+/// Relationships derived from the foreign keys of a database, in both directions
+#[derive(Clone, Debug, Default)]
+pub struct Relationships {
+    relationships: Vec<Relationship>,
+}
+
+impl Relationships {
+    /// Classify every same database constraint of a database
+    pub fn new(db: &Database) -> Relationships {
+        let mut constraints = db
+            .constraints()
+            .map(|(_, c)| c.clone())
+            .filter(|c| !c.is_cross_database())
+            .collect::<Vec<Arc<Constraint>>>();
+        constraints.sort_by_key(|c| c.name());
+
+        // candidates with a disambiguating suffix used when names collide
+        let mut candidates: Vec<(Relationship, String)> = Vec::new();
+
+        for constraint in constraints.iter() {
+            let (Some(local), Some(foreign)) = (
+                db.table(&constraint.local().table()),
+                db.table(&constraint.foreign().table()),
+            ) else {
+                continue;
+            };
+
+            let stem = column_stem(constraint);
+            let unique = is_unique_key(&local, constraint);
+            let optional = constraint.key_pairs().any(|p| p.local.nullable());
+
+            candidates.push((
+                Relationship {
+                    name: stem.clone(),
+                    kind: if unique {
+                        RelationshipKind::OneToOne
+                    } else {
+                        RelationshipKind::ManyToOne
+                    },
+                    table: local.name(),
+                    related_table: foreign.name(),
+                    constraints: vec![constraint.name()],
+                    through: None,
+                    optional,
+                },
+                stem.clone(),
+            ));
+
+            candidates.push((
+                Relationship {
+                    name: if unique {
+                        singular(&local.name())
+                    } else {
+                        local.name().to_string()
+                    },
+                    kind: if unique {
+                        RelationshipKind::OneToOne
+                    } else {
+                        RelationshipKind::OneToMany
+                    },
+                    table: foreign.name(),
+                    related_table: local.name(),
+                    constraints: vec![constraint.name()],
+                    through: None,
+                    optional: true,
+                },
+                stem,
+            ));
+        }
+
+        for (_, table) in db.tables() {
+            let outgoing = constraints
+                .iter()
+                .filter(|c| c.local().table() == table.name())
+                .collect::<Vec<&Arc<Constraint>>>();
+
+            if outgoing.len() != 2 || !is_junction(table, &outgoing) {
+                continue;
+            }
+
+            for (from, to) in [(outgoing[0], outgoing[1]), (outgoing[1], outgoing[0])] {
+                candidates.push((
+                    Relationship {
+                        name: to.foreign().table().to_string(),
+                        kind: RelationshipKind::ManyToMany,
+                        table: from.foreign().table(),
+                        related_table: to.foreign().table(),
+                        constraints: vec![from.name(), to.name()],
+                        through: Some(table.name()),
+                        optional: true,
+                    },
+                    table.name().to_string(),
+                ));
+            }
+        }
+
+        let relationships = candidates
+            .iter()
+            .map(|(relationship, suffix)| {
+                let collides = candidates
+                    .iter()
+                    .filter(|(r, _)| r.table == relationship.table && r.name == relationship.name)
+                    .count()
+                    > 1;
+
+                let mut relationship = relationship.clone();
+                if collides {
+                    relationship.name = format!("{}_by_{}", relationship.name, suffix);
+                }
+
+                relationship
+            })
+            .collect();
+
+        Relationships { relationships }
+    }
+
+    /// Get relationships iterator
+    pub fn iter(&self) -> std::slice::Iter<'_, Relationship> {
+        self.relationships.iter()
+    }
+
+    /// Get relationships seen from a table
+    pub fn by_table(&self, table: &str) -> Vec<&Relationship> {
+        self.relationships
+            .iter()
+            .filter(|r| r.table.as_str() == table)
+            .collect()
+    }
+
+    /// Find a relationship of a table by its generated name
+    pub fn relationship(&self, table: &str, name: &str) -> Option<&Relationship> {
+        self.relationships
+            .iter()
+            .find(|r| r.table.as_str() == table && r.name == name)
+    }
+}
+
+/// Relation name from the local column, i.e. `client_id` becomes `client`
+fn column_stem(constraint: &Constraint) -> String {
+    if constraint.key_pairs_count() == 1 {
+        let name = constraint.local().name();
+        if let Some(stem) = name.strip_suffix("_id").filter(|s| !s.is_empty()) {
+            return stem.to_string();
+        }
+    }
+
+    singular(&constraint.foreign().table())
+}
+
+/// Naive english singular of a table name, i.e. `categories` becomes `category`
+fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if name.ends_with("ss") {
+        name.to_string()
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Check if the local columns of a constraint can hold each value only once
+fn is_unique_key(table: &Table, constraint: &Constraint) -> bool {
+    if constraint.key_pairs_count() == 1 {
+        let column = &constraint.key_pairs().next().unwrap().local;
+        if column.unique() || (column.primary() && table.primary_key_count() == 1) {
+            return true;
+        }
+
+        // a unique index only makes the column unique when it covers nothing else
+        if table
+            .indexes()
+            .any(|(_, i)| i.column().name() == column.name() && i.unique() && i.column_count() == 1)
+        {
+            return true;
+        }
+    }
+
+    table.primary_key_count() > 0
+        && table.primary_key_count() == constraint.key_pairs_count()
+        && constraint
+            .key_pairs()
+            .all(|p| table.primary_keys().any(|k| *k == p.local.name()))
+}
+
+/// Check if a table only links two others, all its columns being foreign or primary keys
+fn is_junction(table: &Table, outgoing: &[&Arc<Constraint>]) -> bool {
+    table.columns().all(|(name, _)| {
+        table.primary_keys().any(|k| k == name)
+            || outgoing
+                .iter()
+                .any(|c| c.key_pairs().any(|p| p.local.name() == *name))
+    })
+}
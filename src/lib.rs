@@ -1,3 +1,7 @@
 pub mod adapter;
+pub mod builder;
+pub mod graph;
+pub mod lint;
 pub mod metadata;
 pub mod reflection;
+pub mod snapshot;
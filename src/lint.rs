@@ -0,0 +1,145 @@
+mod rules;
+
+pub use crate::lint::rules::{
+    CharsetMismatch, FloatForMoney, ForeignKeyTypeMismatch, MissingPrimaryKey, NamingConvention,
+    NullableUniqueColumn, UnindexedForeignKey,
+};
+
+use crate::reflection::Database;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Where in the schema a finding was made
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLocation {
+    Database,
+    Table(Arc<String>),
+    Column(Arc<String>, Arc<String>),
+    Index(Arc<String>, Arc<String>),
+    Constraint(Arc<String>),
+}
+
+impl Display for LintLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintLocation::Database => write!(f, "database"),
+            LintLocation::Table(table) => write!(f, "{}", table),
+            LintLocation::Column(table, column) => write!(f, "{}.{}", table, column),
+            LintLocation::Index(table, index) => write!(f, "{} index {}", table, index),
+            LintLocation::Constraint(constraint) => write!(f, "constraint {}", constraint),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub rule: String,
+    pub severity: Severity,
+    pub location: LintLocation,
+    pub message: String,
+}
+
+impl Display for LintFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} [{}] {}: {}",
+            self.severity, self.rule, self.location, self.message
+        )
+    }
+}
+
+/// Schema convention check run over a whole database
+pub trait LintRule: Send + Sync {
+    /// Unique rule name, used to override its severity
+    fn name(&self) -> &str;
+
+    /// Severity of findings unless overridden
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Find violations as locations with a message
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)>;
+}
+
+/// Runs a set of lint rules over a database
+#[derive(Default)]
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    severities: HashMap<String, Severity>,
+}
+
+impl Linter {
+    /// Create a linter without any rules
+    pub fn new() -> Linter {
+        Linter::default()
+    }
+
+    /// Create a linter with every built-in rule
+    pub fn with_default_rules() -> Linter {
+        let mut linter = Linter::new();
+        linter
+            .add_rule(MissingPrimaryKey)
+            .add_rule(UnindexedForeignKey)
+            .add_rule(ForeignKeyTypeMismatch)
+            .add_rule(CharsetMismatch)
+            .add_rule(NullableUniqueColumn)
+            .add_rule(FloatForMoney::default())
+            .add_rule(NamingConvention);
+
+        linter
+    }
+
+    /// Add a rule
+    pub fn add_rule(&mut self, rule: impl LintRule + 'static) -> &mut Linter {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Override the severity of a rule by name
+    pub fn set_severity(&mut self, rule: impl ToString, severity: Severity) -> &mut Linter {
+        self.severities.insert(rule.to_string(), severity);
+        self
+    }
+
+    /// Get rule names iterator
+    pub fn rule_names(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|r| r.name())
+    }
+
+    /// Run every rule, findings are grouped by rule in the order the rules were added
+    pub fn run(&self, db: &Database) -> Vec<LintFinding> {
+        let mut findings = Vec::new();
+
+        for rule in self.rules.iter() {
+            let severity = self
+                .severities
+                .get(rule.name())
+                .copied()
+                .unwrap_or_else(|| rule.severity());
+
+            for (location, message) in rule.check(db) {
+                findings.push(LintFinding {
+                    rule: rule.name().to_string(),
+                    severity,
+                    location,
+                    message,
+                });
+            }
+        }
+
+        findings
+    }
+}
@@ -0,0 +1,301 @@
+use crate::lint::{LintLocation, LintRule, Severity};
+use crate::metadata::consts::{METADATA_CHARSET, METADATA_COLLATION};
+use crate::reflection::{Constraint, Database, SqlDatatype};
+use std::sync::Arc;
+
+/// Same database constraints, sorted by name
+fn sorted_constraints(db: &Database) -> Vec<Arc<Constraint>> {
+    let mut constraints = db
+        .constraints()
+        .map(|(_, c)| c.clone())
+        .filter(|c| !c.is_cross_database())
+        .collect::<Vec<Arc<Constraint>>>();
+    constraints.sort_by_key(|c| c.name());
+
+    constraints
+}
+
+/// Tables without a primary key
+pub struct MissingPrimaryKey;
+
+impl LintRule for MissingPrimaryKey {
+    fn name(&self) -> &str {
+        "missing_primary_key"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        db.tables()
+            .filter(|(_, t)| t.primary_key_count() == 0)
+            .map(|(name, _)| {
+                (
+                    LintLocation::Table(name.clone()),
+                    String::from("table has no primary key"),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Foreign key columns that are not the first column of any index
+pub struct UnindexedForeignKey;
+
+impl LintRule for UnindexedForeignKey {
+    fn name(&self) -> &str {
+        "unindexed_foreign_key"
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        let mut findings = Vec::new();
+
+        for constraint in sorted_constraints(db) {
+            let Some(table) = db.table(&constraint.local().table()) else {
+                continue;
+            };
+
+            // indexes keep their first column, so this only finds leading columns
+            for pair in constraint.key_pairs() {
+                if table.index_by_column_name(pair.local.name()).is_none() {
+                    findings.push((
+                        LintLocation::Column(pair.local.table(), pair.local.name()),
+                        format!("foreign key {} column is not indexed", constraint.name()),
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// Foreign keys pairing columns of different datatypes or signedness
+pub struct ForeignKeyTypeMismatch;
+
+impl LintRule for ForeignKeyTypeMismatch {
+    fn name(&self) -> &str {
+        "foreign_key_type_mismatch"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        let mut findings = Vec::new();
+
+        for constraint in sorted_constraints(db) {
+            for pair in constraint.key_pairs() {
+                let (local, foreign) = (pair.local.datatype(), pair.foreign.datatype());
+
+                // integer display widths don't matter, anything else has to match exactly
+                let mismatch = !local.is_key_compatible(foreign)
+                    || (local.sign().is_none() && local != foreign);
+
+                if mismatch {
+                    findings.push((
+                        LintLocation::Constraint(constraint.name()),
+                        format!(
+                            "{}.{} is {:?} but references {}.{} of {:?}",
+                            pair.local.table(),
+                            pair.local.name(),
+                            local,
+                            pair.foreign.table(),
+                            pair.foreign.name(),
+                            foreign
+                        ),
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// Text columns with a charset or collation other than their table default
+pub struct CharsetMismatch;
+
+impl LintRule for CharsetMismatch {
+    fn name(&self) -> &str {
+        "charset_mismatch"
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        let mut findings = Vec::new();
+
+        for (table_name, table) in db.tables() {
+            for (column_name, column) in table.columns() {
+                if !column.datatype().is_text() {
+                    continue;
+                }
+
+                for (key, column_value, table_value) in [
+                    (METADATA_CHARSET, column.charset(), table.charset()),
+                    (METADATA_COLLATION, column.collation(), table.collation()),
+                ] {
+                    if let (Some(column_value), Some(table_value)) = (column_value, table_value) {
+                        if column_value != table_value {
+                            findings.push((
+                                LintLocation::Column(table_name.clone(), column_name.clone()),
+                                format!(
+                                    "{} {} differs from table default {}",
+                                    key, column_value, table_value
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// Nullable columns inside unique indexes, which then allow repeated NULLs
+pub struct NullableUniqueColumn;
+
+impl LintRule for NullableUniqueColumn {
+    fn name(&self) -> &str {
+        "nullable_unique_column"
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        let mut findings = Vec::new();
+
+        for (table_name, table) in db.tables() {
+            for (index_name, index) in table.indexes() {
+                if index.unique() && index.column().nullable() {
+                    findings.push((
+                        LintLocation::Index(table_name.clone(), index_name.clone()),
+                        format!(
+                            "unique index includes nullable column {}",
+                            index.column().name()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// FLOAT or DOUBLE columns whose names suggest monetary values
+pub struct FloatForMoney {
+    pub keywords: Vec<String>,
+}
+
+impl Default for FloatForMoney {
+    fn default() -> Self {
+        FloatForMoney {
+            keywords: [
+                "price", "amount", "cost", "total", "balance", "money", "fee", "salary",
+            ]
+            .iter()
+            .map(|k| k.to_string())
+            .collect(),
+        }
+    }
+}
+
+impl LintRule for FloatForMoney {
+    fn name(&self) -> &str {
+        "float_for_money"
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        let mut findings = Vec::new();
+
+        for (table_name, table) in db.tables() {
+            for (column_name, column) in table.columns() {
+                let is_float = matches!(
+                    column.datatype(),
+                    SqlDatatype::Float(..) | SqlDatatype::Double(..)
+                );
+                let name = column_name.to_lowercase();
+
+                if is_float && self.keywords.iter().any(|k| name.contains(k.as_str())) {
+                    findings.push((
+                        LintLocation::Column(table_name.clone(), column_name.clone()),
+                        String::from(
+                            "monetary values should use DECIMAL instead of FLOAT or DOUBLE",
+                        ),
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// Table, column, index and constraint names that are not lowercase snake_case
+pub struct NamingConvention;
+
+impl NamingConvention {
+    fn is_snake_case(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            && !name.contains("__")
+            && !name.ends_with('_')
+    }
+}
+
+impl LintRule for NamingConvention {
+    fn name(&self) -> &str {
+        "naming_convention"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        let mut findings = Vec::new();
+        let message = |kind: &str, name: &str| format!("{} name {} is not snake_case", kind, name);
+
+        for (table_name, table) in db.tables() {
+            if !Self::is_snake_case(table_name) {
+                findings.push((
+                    LintLocation::Table(table_name.clone()),
+                    message("table", table_name),
+                ));
+            }
+
+            for (column_name, _) in table.columns() {
+                if !Self::is_snake_case(column_name) {
+                    findings.push((
+                        LintLocation::Column(table_name.clone(), column_name.clone()),
+                        message("column", column_name),
+                    ));
+                }
+            }
+
+            for (index_name, index) in table.indexes() {
+                if !index.primary() && !Self::is_snake_case(index_name) {
+                    findings.push((
+                        LintLocation::Index(table_name.clone(), index_name.clone()),
+                        message("index", index_name),
+                    ));
+                }
+            }
+        }
+
+        for constraint in sorted_constraints(db) {
+            if !Self::is_snake_case(&constraint.name()) {
+                findings.push((
+                    LintLocation::Constraint(constraint.name()),
+                    message("constraint", &constraint.name()),
+                ));
+            }
+        }
+
+        findings
+    }
+}
@@ -1,4 +1,9 @@
 pub mod consts;
+mod ordered;
+mod overlay;
 mod with_metadata;
 
-pub use crate::metadata::with_metadata::WithMetadata;
+pub(crate) use crate::metadata::ordered::serialize_sorted;
+pub use crate::metadata::overlay::{Overlay, OverlayError};
+pub(crate) use crate::metadata::with_metadata::{absorb_typed_metadata, metadata_entries};
+pub use crate::metadata::with_metadata::{meta_flag_value, meta_text_value, WithMetadata};
@@ -0,0 +1,15 @@
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Serialize a map with its keys sorted, so the output does not change between runs
+pub(crate) fn serialize_sorted<'a, S, M, K, V>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Ord + Serialize + 'a,
+    V: Serialize + 'a,
+{
+    map.into_iter()
+        .collect::<BTreeMap<&K, &V>>()
+        .serialize(serializer)
+}
@@ -0,0 +1,214 @@
+use crate::metadata::consts::{
+    METADATA_CHARSET, METADATA_COLLATION, METADATA_FLAG_AUTO_INCREMENT, METADATA_FLAG_NULLABLE,
+    METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP, METADATA_FLAG_PRIMARY, METADATA_FLAG_UNIQUE,
+    METADATA_FLAG_UNSIGNED, METADATA_ON_UPDATE,
+};
+use crate::metadata::WithMetadata;
+use crate::reflection::Database;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Failure to load an overlay or to merge it into a database
+#[derive(Clone, Debug, PartialEq)]
+pub enum OverlayError {
+    /// Overlay file could not be read
+    Io(String),
+    /// Overlay is not a map of entries to metadata
+    Parse(String),
+    /// File extension without a parser, YAML and TOML need the `yaml` and `toml` features
+    UnsupportedFormat(String),
+    /// Entry refers to a table that is not in the database, views are not valid targets
+    MissingTable { table: String },
+    /// Entry refers to a column that is not in its table
+    MissingColumn { table: String, column: String },
+    /// Entry sets a key backed by a typed field, which would change the schema
+    ReservedKey { target: String, key: String },
+}
+
+/// Keys backed by typed fields of tables and columns, or describing the column type
+const RESERVED_KEYS: [&str; 9] = [
+    METADATA_FLAG_UNSIGNED,
+    METADATA_FLAG_NULLABLE,
+    METADATA_FLAG_PRIMARY,
+    METADATA_FLAG_UNIQUE,
+    METADATA_FLAG_AUTO_INCREMENT,
+    METADATA_CHARSET,
+    METADATA_COLLATION,
+    METADATA_ON_UPDATE,
+    METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP,
+];
+
+impl Display for OverlayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OverlayError::Io(e) => write!(f, "failed to read overlay: {}", e),
+            OverlayError::Parse(e) => write!(f, "failed to parse overlay: {}", e),
+            OverlayError::UnsupportedFormat(extension) => {
+                write!(f, "unsupported overlay format {}", extension)
+            }
+            OverlayError::MissingTable { table } => {
+                write!(f, "overlay refers to missing table {}", table)
+            }
+            OverlayError::MissingColumn { table, column } => {
+                write!(f, "overlay refers to missing column {}.{}", table, column)
+            }
+            OverlayError::ReservedKey { target, key } => {
+                write!(f, "overlay sets reserved key {} of {}", key, target)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OverlayError {}
+
+/// Metadata kept outside the database, keyed by `table` or `table.column`.
+/// In TOML the dotted keys need quoting, i.e. `["clients.email"]`.
+/// Views are not supported as targets.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Overlay {
+    entries: IndexMap<String, IndexMap<String, Value>>,
+}
+
+impl Overlay {
+    /// Create an empty overlay
+    pub fn new() -> Overlay {
+        Overlay::default()
+    }
+
+    /// Add a metadata value for a `table` or `table.column` entry
+    pub fn set(
+        &mut self,
+        target: impl ToString,
+        key: impl ToString,
+        value: impl Into<Value>,
+    ) -> &mut Overlay {
+        self.entries
+            .entry(target.to_string())
+            .or_default()
+            .insert(key.to_string(), value.into());
+
+        self
+    }
+
+    /// Get entries iterator
+    pub fn entries(&self) -> indexmap::map::Iter<'_, String, IndexMap<String, Value>> {
+        self.entries.iter()
+    }
+
+    /// Parse an overlay from JSON
+    pub fn from_json(contents: &str) -> Result<Overlay, OverlayError> {
+        serde_json::from_str(contents).map_err(|e| OverlayError::Parse(e.to_string()))
+    }
+
+    /// Parse an overlay from YAML
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(contents: &str) -> Result<Overlay, OverlayError> {
+        serde_yaml::from_str(contents).map_err(|e| OverlayError::Parse(e.to_string()))
+    }
+
+    /// Parse an overlay from TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml(contents: &str) -> Result<Overlay, OverlayError> {
+        toml::from_str(contents).map_err(|e| OverlayError::Parse(e.to_string()))
+    }
+
+    /// Read an overlay file, picking the format by its extension
+    pub fn load(path: impl AsRef<Path>) -> Result<Overlay, OverlayError> {
+        let path = path.as_ref();
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| OverlayError::Io(e.to_string()))?;
+
+        match path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+        {
+            "json" => Overlay::from_json(&contents),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Overlay::from_yaml(&contents),
+            #[cfg(feature = "toml")]
+            "toml" => Overlay::from_toml(&contents),
+            extension => Err(OverlayError::UnsupportedFormat(extension.to_string())),
+        }
+    }
+
+    /// Check that every entry refers to a table or column of the database
+    /// and leaves keys backed by typed fields alone
+    pub fn check(&self, db: &Database) -> Result<(), Vec<OverlayError>> {
+        let mut issues = Vec::new();
+
+        for (target, metadata) in self.entries.iter() {
+            let (table_name, column_name) = split_target(target);
+
+            match (db.table(table_name), column_name) {
+                (None, _) => issues.push(OverlayError::MissingTable {
+                    table: table_name.to_string(),
+                }),
+                (Some(table), Some(column_name)) if table.column(column_name).is_none() => issues
+                    .push(OverlayError::MissingColumn {
+                        table: table_name.to_string(),
+                        column: column_name.to_string(),
+                    }),
+                _ => {}
+            }
+
+            issues.extend(
+                metadata
+                    .keys()
+                    .filter(|key| RESERVED_KEYS.contains(&key.as_str()))
+                    .map(|key| OverlayError::ReservedKey {
+                        target: target.to_string(),
+                        key: key.to_string(),
+                    }),
+            );
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Merge the overlay into table and column metadata, replacing values of the same keys.
+    /// Nothing is merged when an entry refers to a missing table or column, or sets a reserved key.
+    pub fn apply(&self, db: &mut Database) -> Result<(), Vec<OverlayError>> {
+        self.check(db)?;
+
+        for (target, metadata) in self.entries.iter() {
+            let (table_name, column_name) = split_target(target);
+            let Some(table) = db.table_mut(table_name) else {
+                continue;
+            };
+
+            for (key, value) in metadata {
+                match column_name {
+                    Some(column_name) => {
+                        if let Some(column) = table.column_mut(column_name) {
+                            column.set_meta_value(key, value.clone());
+                        }
+                    }
+                    None => {
+                        table.set_meta_value(key, value.clone());
+                    }
+                }
+            }
+        }
+
+        db.relink();
+
+        Ok(())
+    }
+}
+
+/// Split `table.column` into its parts, a bare name targets the table
+fn split_target(target: &str) -> (&str, Option<&str>) {
+    match target.split_once('.') {
+        Some((table, column)) => (table, Some(column)),
+        None => (target, None),
+    }
+}
@@ -1,35 +1,199 @@
-use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
 
+/// Key value metadata of reflected items.
+///
+/// Text values live in the string container, structured values like lists and objects
+/// in a separate one, when the implementor provides it.
+///
+/// Keys backed by typed fields, i.e. `nullable`, `primary`, `unique`, `auto_increment`,
+/// `charset`, `collation` and `on_update` of a column, are never stored in the containers.
+/// Read and write them through [`meta`](WithMetadata::meta) and
+/// [`set_meta`](WithMetadata::set_meta) or the typed getters and setters, removing them from
+/// [`get_metadata_mut`](WithMetadata::get_metadata_mut) has no effect.
 pub trait WithMetadata {
-    /// Borrow metadata container for reading
+    /// Borrow metadata container for reading, without keys backed by typed fields
     fn get_metadata(&self) -> &HashMap<String, String>;
 
-    /// Borrow metadata container for writing
+    /// Borrow metadata container for writing, without keys backed by typed fields
     fn get_metadata_mut(&mut self) -> &mut HashMap<String, String>;
 
+    /// Borrow structured metadata container for reading, None if not supported
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        None
+    }
+
+    /// Borrow structured metadata container for writing, None if not supported,
+    /// structured values are then stored as JSON text
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        None
+    }
+
+    /// Read a key backed by a typed field, None if the key is not one of them
+    fn typed_meta(&self, _key: &str) -> Option<Option<String>> {
+        None
+    }
+
+    /// Write a key backed by a typed field, false if the key is not one of them.
+    /// See [`meta_flag_value`] and [`meta_text_value`] for reading the value.
+    fn set_typed_meta(&mut self, _key: &str, _value: &Value) -> bool {
+        false
+    }
+
     /// Set metadata key value pair
     fn set_meta(&mut self, meta_key: impl ToString, meta_value: impl ToString) -> &mut Self {
-        self.get_metadata_mut()
-            .insert(meta_key.to_string(), meta_value.to_string());
-
-        self
+        self.set_meta_value(meta_key, meta_value.to_string())
     }
 
     /// Set metadata flag
     fn set_meta_flag(&mut self, meta_flag: impl ToString) -> &mut Self {
-        self.get_metadata_mut()
-            .insert(meta_flag.to_string(), "1".to_string());
-
-        self
+        self.set_meta(meta_flag, "1")
     }
 
     /// Check if metadata flag is set
     fn meta_flag(&self, flag: &str) -> bool {
-        self.get_metadata().contains_key(flag)
+        self.meta_value(flag).is_some()
     }
 
-    /// Get metadata value by key
+    /// Get metadata value by key, structured values are returned as JSON text
     fn meta(&self, key: &str) -> Option<String> {
-        self.get_metadata().get(key).cloned()
+        self.meta_value(key).map(|value| value_to_string(&value))
     }
+
+    /// Set a structured metadata value, i.e. a list or an object
+    fn set_meta_value(
+        &mut self,
+        meta_key: impl ToString,
+        meta_value: impl Into<Value>,
+    ) -> &mut Self {
+        let (meta_key, meta_value) = (meta_key.to_string(), meta_value.into());
+
+        if self.set_typed_meta(&meta_key, &meta_value) {
+            return self;
+        }
+
+        if let Some(values) = self.get_metadata_values_mut() {
+            values.remove(&meta_key);
+        }
+
+        match meta_value {
+            Value::String(text) => {
+                self.get_metadata_mut().insert(meta_key, text);
+            }
+            structured => {
+                self.get_metadata_mut().remove(&meta_key);
+
+                match self.get_metadata_values_mut() {
+                    Some(values) => {
+                        values.insert(meta_key, structured);
+                    }
+                    None => {
+                        self.get_metadata_mut()
+                            .insert(meta_key, structured.to_string());
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Get metadata value by key as stored, text values as JSON strings
+    fn meta_value(&self, key: &str) -> Option<Value> {
+        if let Some(value) = self.typed_meta(key) {
+            return value.map(Value::String);
+        }
+
+        match self.get_metadata().get(key) {
+            Some(text) => Some(Value::String(text.clone())),
+            None => self.get_metadata_values()?.get(key).cloned(),
+        }
+    }
+
+    /// Serialize any value into metadata
+    fn set_meta_as<T: Serialize>(
+        &mut self,
+        meta_key: impl ToString,
+        meta_value: &T,
+    ) -> Result<&mut Self, serde_json::Error> {
+        let meta_value = serde_json::to_value(meta_value)?;
+
+        Ok(self.set_meta_value(meta_key, meta_value))
+    }
+
+    /// Deserialize a metadata value into the requested type, None if the key is missing.
+    /// Text that doesn't fit the type is parsed as JSON, as written without a structured container.
+    fn meta_as<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, serde_json::Error> {
+        let Some(value) = self.meta_value(key) else {
+            return Ok(None);
+        };
+
+        match serde_json::from_value(value.clone()) {
+            Ok(parsed) => Ok(Some(parsed)),
+            Err(e) => match value {
+                Value::String(text) => serde_json::from_str(&text).map(Some).map_err(|_| e),
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+/// Text form of a metadata value, strings as they are and anything else as JSON
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Flag value of a typed key, false and null clear it, numbers set it unless zero
+/// and any other value sets it
+pub fn meta_flag_value(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        _ => true,
+    }
+}
+
+/// Text value of a typed key, null clears it
+pub fn meta_text_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        other => Some(value_to_string(other)),
+    }
+}
+
+/// Move entries of well known keys out of the metadata container into their typed fields,
+/// i.e. after deserializing data written before the fields existed
+pub(crate) fn absorb_typed_metadata(item: &mut impl WithMetadata) {
+    let entries = item
+        .get_metadata()
+        .iter()
+        .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+        .collect::<Vec<(String, Value)>>();
+
+    for (key, value) in entries {
+        if item.set_typed_meta(&key, &value) {
+            item.get_metadata_mut().remove(&key);
+        }
+    }
+}
+
+/// Text and structured metadata together, sorted by key
+pub(crate) fn metadata_entries(item: &impl WithMetadata) -> BTreeMap<String, Value> {
+    let mut entries = item
+        .get_metadata()
+        .iter()
+        .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+        .collect::<BTreeMap<String, Value>>();
+
+    if let Some(values) = item.get_metadata_values() {
+        entries.extend(values.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    entries
 }
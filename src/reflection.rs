@@ -1,11 +1,22 @@
+mod catalog;
+mod check_constraint;
 mod column;
 mod constraint;
 mod database;
 mod datatypes;
+mod event;
 mod index;
+mod integrity;
+mod name;
+mod partition;
+mod routine;
 mod table;
+mod trigger;
+mod view;
 
-pub use crate::reflection::column::Column;
+pub use crate::reflection::catalog::Catalog;
+pub use crate::reflection::check_constraint::CheckConstraint;
+pub use crate::reflection::column::{Column, ColumnGeneration, GeneratedKind};
 pub use crate::reflection::constraint::Constraint;
 pub use crate::reflection::constraint::ConstraintKeyPair;
 pub use crate::reflection::constraint::ConstraintSide;
@@ -14,5 +25,11 @@ pub use crate::reflection::datatypes::{
     DefaultValue, JsonDatatype, JsonNumber, JsonString, ParseDatatypeError, RustDatatype,
     SqlDatatype, SqlSigned,
 };
+pub use crate::reflection::event::Event;
 pub use crate::reflection::index::Index;
+pub use crate::reflection::integrity::IntegrityIssue;
+pub use crate::reflection::partition::Partition;
+pub use crate::reflection::routine::{ParameterMode, Routine, RoutineKind, RoutineParameter};
 pub use crate::reflection::table::Table;
+pub use crate::reflection::trigger::Trigger;
+pub use crate::reflection::view::View;
@@ -0,0 +1,149 @@
+use crate::metadata::serialize_sorted;
+use crate::metadata::WithMetadata;
+use crate::reflection::constraint::Constraint;
+use crate::reflection::database::Database;
+use crate::reflection::name::Name;
+use crate::reflection::table::Table;
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct Catalog {
+    databases: IndexMap<Arc<String>, Arc<Database>>,
+    #[serde(serialize_with = "serialize_sorted")]
+    metadata: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    metadata_values: HashMap<String, Value>,
+}
+
+impl WithMetadata for Catalog {
+    /// Borrow metadata container for reading
+    fn get_metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Borrow metadata container for writing
+    fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.metadata
+    }
+
+    /// Borrow structured metadata container for reading
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    /// Borrow structured metadata container for writing
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
+}
+
+impl Serialize for Catalog {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Catalog::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Catalog {
+    /// Deserialize and relink, so shared columns are shared again
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut catalog = Catalog::deserialize(deserializer)?;
+        catalog.relink();
+
+        Ok(catalog)
+    }
+}
+
+impl Catalog {
+    /// Create a new empty catalog
+    pub fn new() -> Catalog {
+        Catalog::default()
+    }
+
+    /// Add a database to the catalog
+    pub fn set_database(&mut self, database: Database) -> &mut Catalog {
+        self.databases
+            .insert(Arc::new(database.name().to_string()), Arc::new(database));
+
+        self
+    }
+
+    /// Get a database by name
+    pub fn database(&self, key: &str) -> Option<Arc<Database>> {
+        self.databases.get(&Name(key)).cloned()
+    }
+
+    /// Get databases iterator
+    pub fn databases(&self) -> indexmap::map::Iter<'_, Arc<String>, Arc<Database>> {
+        self.databases.iter()
+    }
+
+    /// Get a table by database and table name
+    pub fn table(&self, database: &str, table: &str) -> Option<Arc<Table>> {
+        self.database(database).and_then(|d| d.table(table))
+    }
+
+    /// Get the foreign table of a constraint defined in the given database
+    pub fn foreign_table(&self, database: &str, constraint: &Constraint) -> Option<Arc<Table>> {
+        let foreign_database = constraint
+            .foreign_database()
+            .map(|d| d.to_string())
+            .unwrap_or(database.to_string());
+
+        self.table(&foreign_database, constraint.foreign().table().as_str())
+    }
+
+    /// Relink every database, then point cross database constraints at the foreign columns
+    /// held by the other databases of the catalog
+    pub fn relink(&mut self) -> &mut Catalog {
+        for db in self.databases.values_mut() {
+            Arc::make_mut(db).relink();
+        }
+
+        for (name, constraint) in self.cross_database_constraints() {
+            let Some(foreign_database) = constraint
+                .foreign_database()
+                .and_then(|d| self.database(d.as_str()))
+            else {
+                continue;
+            };
+
+            let mut constraint = (*constraint).clone();
+            for pair in constraint.key_pairs_mut() {
+                if let Some(foreign) = foreign_database
+                    .table(pair.foreign.table().as_str())
+                    .and_then(|t| t.column(pair.foreign.name().as_str()))
+                {
+                    pair.foreign = foreign;
+                }
+            }
+
+            if let Some(db) = self.databases.get_mut(&name) {
+                Arc::make_mut(db).set_constraint(constraint);
+            }
+        }
+
+        self
+    }
+
+    /// Get all constraints referencing a table in another database, paired with the local database name
+    pub fn cross_database_constraints(&self) -> Vec<(Arc<String>, Arc<Constraint>)> {
+        self.databases
+            .iter()
+            .flat_map(|(name, db)| {
+                db.constraints()
+                    .filter(|(_, c)| c.is_cross_database())
+                    .map(|(_, c)| (name.clone(), c.clone()))
+                    .collect::<Vec<(Arc<String>, Arc<Constraint>)>>()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CheckConstraint {
+    name: Arc<String>,
+    table: Arc<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<Arc<String>>,
+    expression: String,
+}
+
+impl CheckConstraint {
+    /// Create a table level check constraint
+    pub fn new(name: impl ToString, table: impl ToString, expression: impl ToString) -> Self {
+        CheckConstraint {
+            name: Arc::new(name.to_string()),
+            table: Arc::new(table.to_string()),
+            expression: expression.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the column for column level check constraints
+    pub fn set_column(&mut self, column: Option<impl ToString>) -> &mut CheckConstraint {
+        self.column = column.map(|c| Arc::new(c.to_string()));
+        self
+    }
+
+    /// Set constraint name
+    pub(crate) fn set_name(&mut self, name: impl ToString) -> &mut CheckConstraint {
+        self.name = Arc::new(name.to_string());
+        self
+    }
+
+    /// Set check expression
+    pub(crate) fn set_expression(&mut self, expression: impl ToString) -> &mut CheckConstraint {
+        self.expression = expression.to_string();
+        self
+    }
+
+    /// Set table name
+    pub(crate) fn set_table(&mut self, table: impl ToString) -> &mut CheckConstraint {
+        self.table = Arc::new(table.to_string());
+        self
+    }
+
+    /// Get constraint name
+    pub fn name(&self) -> Arc<String> {
+        self.name.clone()
+    }
+
+    /// Get table name
+    pub fn table(&self) -> Arc<String> {
+        self.table.clone()
+    }
+
+    /// Get column name for column level check constraints
+    pub fn column(&self) -> Option<Arc<String>> {
+        self.column.clone()
+    }
+
+    /// Get check expression
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+}
@@ -1,12 +1,34 @@
-use crate::metadata::consts::METADATA_FLAG_UNSIGNED;
+use crate::metadata::consts::{
+    METADATA_CHARSET, METADATA_COLLATION, METADATA_FLAG_AUTO_INCREMENT, METADATA_FLAG_NULLABLE,
+    METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP, METADATA_FLAG_PRIMARY, METADATA_FLAG_UNIQUE,
+    METADATA_FLAG_UNSIGNED, METADATA_ON_UPDATE,
+};
 use crate::metadata::WithMetadata;
+use crate::metadata::{absorb_typed_metadata, meta_flag_value, meta_text_value, serialize_sorted};
 use crate::reflection::datatypes::{DefaultValue, JsonDatatype, RustDatatype, SqlDatatype};
 use crate::reflection::SqlSigned;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// Generated column storage, VIRTUAL is computed on read, STORED (PERSISTENT) on write
+pub enum GeneratedKind {
+    #[default]
+    Virtual,
+    Stored,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColumnGeneration {
+    pub kind: GeneratedKind,
+    pub expression: String,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Column {
     table: Arc<String>,
     name: Arc<String>,
@@ -15,7 +37,34 @@ pub struct Column {
     datatype_rust: RustDatatype,
     #[serde(skip_serializing_if = "Option::is_none")]
     default: Option<DefaultValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation: Option<ColumnGeneration>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    invisible: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    nullable: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    primary: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    unique: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    auto_increment: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_update: Option<String>,
+    #[serde(serialize_with = "serialize_sorted")]
     metadata: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    metadata_values: HashMap<String, Value>,
 }
 
 impl WithMetadata for Column {
@@ -28,6 +77,76 @@ impl WithMetadata for Column {
     fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
         &mut self.metadata
     }
+
+    /// Borrow structured metadata container for reading
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    /// Borrow structured metadata container for writing
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
+
+    /// Read nullable, primary, unique, auto increment, charset, collation and on update
+    /// through their metadata keys
+    fn typed_meta(&self, key: &str) -> Option<Option<String>> {
+        let flag = |set: bool| set.then(|| String::from("1"));
+
+        match key {
+            METADATA_FLAG_NULLABLE => Some(flag(self.nullable)),
+            METADATA_FLAG_PRIMARY => Some(flag(self.primary)),
+            METADATA_FLAG_UNIQUE => Some(flag(self.unique)),
+            METADATA_FLAG_AUTO_INCREMENT => Some(flag(self.auto_increment)),
+            METADATA_CHARSET => Some(self.charset.clone()),
+            METADATA_COLLATION => Some(self.collation.clone()),
+            METADATA_ON_UPDATE => Some(self.on_update.clone()),
+            METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP => {
+                Some(flag(self.on_update_current_timestamp()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Write nullable, primary, unique, auto increment, charset, collation and on update
+    /// through their metadata keys
+    fn set_typed_meta(&mut self, key: &str, value: &Value) -> bool {
+        match key {
+            METADATA_FLAG_NULLABLE => self.nullable = meta_flag_value(value),
+            METADATA_FLAG_PRIMARY => self.primary = meta_flag_value(value),
+            METADATA_FLAG_UNIQUE => self.unique = meta_flag_value(value),
+            METADATA_FLAG_AUTO_INCREMENT => self.auto_increment = meta_flag_value(value),
+            METADATA_CHARSET => self.charset = meta_text_value(value),
+            METADATA_COLLATION => self.collation = meta_text_value(value),
+            METADATA_ON_UPDATE => self.on_update = meta_text_value(value),
+            METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP => {
+                if meta_flag_value(value) {
+                    self.on_update = Some(String::from("current_timestamp()"))
+                } else if self.on_update_current_timestamp() {
+                    self.on_update = None
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl Serialize for Column {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Column::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Column {
+    /// Deserialize, moving well known metadata keys of older output into their typed fields
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut column = Column::deserialize(deserializer)?;
+        absorb_typed_metadata(&mut column);
+
+        Ok(column)
+    }
 }
 
 impl Column {
@@ -49,12 +168,45 @@ impl Column {
         c
     }
 
+    /// Set column name, tables keep their column map in sync through `Table::rename_column`
+    pub(crate) fn set_name(&mut self, name: impl ToString) -> &mut Column {
+        self.name = Arc::new(name.to_string());
+        self
+    }
+
+    /// Set owning table name, kept in sync through `Table::rename`
+    pub(crate) fn set_table(&mut self, table: impl ToString) -> &mut Column {
+        self.table = Arc::new(table.to_string());
+        self
+    }
+
     /// Set an optional default value
     pub fn set_default(&mut self, value: Option<DefaultValue>) -> &mut Column {
         self.default = value;
         self
     }
 
+    /// Set an optional column comment
+    pub fn set_comment(&mut self, comment: Option<impl ToString>) -> &mut Column {
+        self.comment = comment.map(|c| c.to_string());
+        self
+    }
+
+    /// Mark the column as generated from an expression
+    pub fn set_generated(&mut self, kind: GeneratedKind, expression: impl ToString) -> &mut Column {
+        self.generation = Some(ColumnGeneration {
+            kind,
+            expression: expression.to_string(),
+        });
+        self
+    }
+
+    /// Set the INVISIBLE flag, invisible columns are omitted from SELECT *
+    pub fn set_invisible(&mut self, invisible: bool) -> &mut Column {
+        self.invisible = invisible;
+        self
+    }
+
     /// Get table name
     pub fn table(&self) -> Arc<String> {
         self.table.clone()
@@ -84,4 +236,108 @@ impl Column {
     pub fn default(&self) -> Option<DefaultValue> {
         self.default.clone()
     }
+
+    /// Get column comment if available
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Get generation kind and expression if the column is generated
+    pub fn generation(&self) -> Option<&ColumnGeneration> {
+        self.generation.as_ref()
+    }
+
+    /// Check if the column is generated, such columns can't be written to
+    pub fn is_generated(&self) -> bool {
+        self.generation.is_some()
+    }
+
+    /// Check if the column is invisible
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
+
+    /// Set whether the column accepts NULL
+    pub fn set_nullable(&mut self, nullable: bool) -> &mut Column {
+        self.nullable = nullable;
+        self
+    }
+
+    /// Check if the column accepts NULL
+    pub fn nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Set whether the column is part of the primary key
+    pub fn set_primary(&mut self, primary: bool) -> &mut Column {
+        self.primary = primary;
+        self
+    }
+
+    /// Check if the column is part of the primary key
+    pub fn primary(&self) -> bool {
+        self.primary
+    }
+
+    /// Set whether the column has a unique index of its own
+    pub fn set_unique(&mut self, unique: bool) -> &mut Column {
+        self.unique = unique;
+        self
+    }
+
+    /// Check if the column has a unique index of its own
+    pub fn unique(&self) -> bool {
+        self.unique
+    }
+
+    /// Set whether the column is AUTO_INCREMENT
+    pub fn set_auto_increment(&mut self, auto_increment: bool) -> &mut Column {
+        self.auto_increment = auto_increment;
+        self
+    }
+
+    /// Check if the column is AUTO_INCREMENT
+    pub fn auto_increment(&self) -> bool {
+        self.auto_increment
+    }
+
+    /// Set character set
+    pub fn set_charset(&mut self, charset: Option<impl ToString>) -> &mut Column {
+        self.charset = charset.map(|c| c.to_string());
+        self
+    }
+
+    /// Get character set if available
+    pub fn charset(&self) -> Option<&str> {
+        self.charset.as_deref()
+    }
+
+    /// Set collation
+    pub fn set_collation(&mut self, collation: Option<impl ToString>) -> &mut Column {
+        self.collation = collation.map(|c| c.to_string());
+        self
+    }
+
+    /// Get collation if available
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
+    /// Set ON UPDATE expression, i.e. `current_timestamp()`
+    pub fn set_on_update(&mut self, on_update: Option<impl ToString>) -> &mut Column {
+        self.on_update = on_update.map(|o| o.to_string());
+        self
+    }
+
+    /// Get ON UPDATE expression if available
+    pub fn on_update(&self) -> Option<&str> {
+        self.on_update.as_deref()
+    }
+
+    /// Check if the column is set to the current timestamp on update
+    pub fn on_update_current_timestamp(&self) -> bool {
+        self.on_update
+            .as_deref()
+            .is_some_and(|o| o.eq_ignore_ascii_case("current_timestamp()"))
+    }
 }
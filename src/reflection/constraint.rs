@@ -1,8 +1,10 @@
+use crate::metadata::serialize_sorted;
 use crate::metadata::WithMetadata;
 use crate::reflection::column::Column;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
-use std::slice::Iter;
+use std::slice::{Iter, IterMut};
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -21,7 +23,16 @@ pub struct ConstraintKeyPair {
 pub struct Constraint {
     name: Arc<String>,
     key_pairs: Vec<ConstraintKeyPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreign_database: Option<Arc<String>>,
+    #[serde(serialize_with = "serialize_sorted")]
     metadata: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    metadata_values: HashMap<String, Value>,
 }
 
 impl WithMetadata for Constraint {
@@ -34,6 +45,16 @@ impl WithMetadata for Constraint {
     fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
         &mut self.metadata
     }
+
+    /// Borrow structured metadata container for reading
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    /// Borrow structured metadata container for writing
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
 }
 
 impl Constraint {
@@ -46,6 +67,12 @@ impl Constraint {
         }
     }
 
+    /// Set constraint name
+    pub(crate) fn set_name(&mut self, name: impl ToString) -> &mut Constraint {
+        self.name = Arc::new(name.to_string());
+        self
+    }
+
     /// Get constraint name
     pub fn name(&self) -> Arc<String> {
         self.name.clone()
@@ -61,6 +88,26 @@ impl Constraint {
         &self.key_pairs.first().unwrap().foreign
     }
 
+    /// Set the database of the foreign columns, when it's not the local database
+    pub fn set_foreign_database(
+        &mut self,
+        foreign_database: Option<impl ToString>,
+    ) -> &mut Constraint {
+        self.foreign_database = foreign_database.map(|d| Arc::new(d.to_string()));
+
+        self
+    }
+
+    /// Get the database of the foreign columns, if it's not the local database
+    pub fn foreign_database(&self) -> Option<Arc<String>> {
+        self.foreign_database.clone()
+    }
+
+    /// Check if the constraint references a table in another database
+    pub fn is_cross_database(&self) -> bool {
+        self.foreign_database.is_some()
+    }
+
     /// Add a local/foreign column pair
     pub fn add_key_pair(&mut self, local: Arc<Column>, foreign: Arc<Column>) -> &mut Constraint {
         self.key_pairs.push(ConstraintKeyPair { local, foreign });
@@ -73,6 +120,11 @@ impl Constraint {
         self.key_pairs.iter()
     }
 
+    /// Get mutable column pairs iterator
+    pub(crate) fn key_pairs_mut(&mut self) -> IterMut<'_, ConstraintKeyPair> {
+        self.key_pairs.iter_mut()
+    }
+
     /// Get number of pairs
     pub fn key_pairs_count(&self) -> usize {
         self.key_pairs.len()
@@ -1,19 +1,58 @@
+use crate::graph::JoinPath;
 use crate::metadata::consts::{METADATA_CHARSET, METADATA_COLLATION};
 use crate::metadata::WithMetadata;
+use crate::metadata::{absorb_typed_metadata, meta_text_value, serialize_sorted};
 use crate::reflection::column::Column;
 use crate::reflection::constraint::{Constraint, ConstraintSide};
+use crate::reflection::event::Event;
+use crate::reflection::index::Index;
+use crate::reflection::integrity::IntegrityIssue;
+use crate::reflection::name::{Name, RoutineName};
+use crate::reflection::routine::{Routine, RoutineKind};
 use crate::reflection::table::Table;
+use crate::reflection::trigger::Trigger;
+use crate::reflection::view::View;
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Procedures and functions have separate namespaces
+type RoutineKey = (RoutineKind, Arc<String>);
+
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Database {
     name: String,
     tables: IndexMap<Arc<String>, Arc<Table>>,
-    constraints: HashMap<Arc<String>, Arc<Constraint>>,
+    #[serde(serialize_with = "serialize_sorted")]
+    constraints: IndexMap<Arc<String>, Arc<Constraint>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    views: IndexMap<Arc<String>, Arc<View>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    triggers: IndexMap<Arc<String>, Arc<Trigger>>,
+    #[serde(
+        default,
+        skip_serializing_if = "IndexMap::is_empty",
+        serialize_with = "serialize_routines",
+        deserialize_with = "deserialize_routines"
+    )]
+    routines: IndexMap<RoutineKey, Arc<Routine>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    events: IndexMap<Arc<String>, Arc<Event>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<String>,
+    #[serde(serialize_with = "serialize_sorted")]
     metadata: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    metadata_values: HashMap<String, Value>,
 }
 
 impl WithMetadata for Database {
@@ -26,6 +65,54 @@ impl WithMetadata for Database {
     fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
         &mut self.metadata
     }
+
+    /// Borrow structured metadata container for reading
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    /// Borrow structured metadata container for writing
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
+
+    /// Read charset and collation through their metadata keys
+    fn typed_meta(&self, key: &str) -> Option<Option<String>> {
+        match key {
+            METADATA_CHARSET => Some(self.charset.clone()),
+            METADATA_COLLATION => Some(self.collation.clone()),
+            _ => None,
+        }
+    }
+
+    /// Write charset and collation through their metadata keys
+    fn set_typed_meta(&mut self, key: &str, value: &Value) -> bool {
+        match key {
+            METADATA_CHARSET => self.charset = meta_text_value(value),
+            METADATA_COLLATION => self.collation = meta_text_value(value),
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl Serialize for Database {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Database::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Database {
+    /// Deserialize and relink, so shared columns are shared again, moving well known
+    /// metadata keys of older output into their typed fields
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut db = Database::deserialize(deserializer)?;
+        absorb_typed_metadata(&mut db);
+        db.relink();
+
+        Ok(db)
+    }
 }
 
 impl Database {
@@ -42,22 +129,38 @@ impl Database {
         &self.name
     }
 
+    /// Set default character set
+    pub fn set_charset(&mut self, charset: Option<impl ToString>) -> &mut Database {
+        self.charset = charset.map(|c| c.to_string());
+        self
+    }
+
+    /// Get default character set if available
+    pub fn charset(&self) -> Option<&str> {
+        self.charset.as_deref()
+    }
+
+    /// Set default collation
+    pub fn set_collation(&mut self, collation: Option<impl ToString>) -> &mut Database {
+        self.collation = collation.map(|c| c.to_string());
+        self
+    }
+
+    /// Get default collation if available
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
     /// Add a table to the database
     pub fn set_table(&mut self, mut table: Table) -> &mut Database {
-        if table.meta(METADATA_CHARSET).is_none()
-            && table.meta(METADATA_COLLATION).is_none()
-            && self.meta(METADATA_CHARSET).is_some()
-            && self.meta(METADATA_COLLATION).is_some()
+        if table.charset().is_none()
+            && table.collation().is_none()
+            && self.charset.is_some()
+            && self.collation.is_some()
         {
             table
-                .set_meta(
-                    METADATA_CHARSET,
-                    self.meta(METADATA_CHARSET).unwrap_or_default(),
-                )
-                .set_meta(
-                    METADATA_COLLATION,
-                    self.meta(METADATA_COLLATION).unwrap_or_default(),
-                );
+                .set_charset(self.charset.as_deref())
+                .set_collation(self.collation.as_deref());
         }
 
         self.tables.insert(table.name(), Arc::new(table));
@@ -67,7 +170,12 @@ impl Database {
 
     /// Get a table by name
     pub fn table(&self, key: &str) -> Option<Arc<Table>> {
-        self.tables.get(&key.to_string()).cloned()
+        self.tables.get(&Name(key)).cloned()
+    }
+
+    /// Borrow a table for writing, constraints keep the previous column instances until relinked
+    pub(crate) fn table_mut(&mut self, key: &str) -> Option<&mut Table> {
+        self.tables.get_mut(&Name(key)).map(Arc::make_mut)
     }
 
     /// Get tables iterator
@@ -75,6 +183,163 @@ impl Database {
         self.tables.iter()
     }
 
+    /// Remove a table along with its triggers and every constraint referencing it
+    pub fn remove_table(&mut self, key: &str) -> Option<Arc<Table>> {
+        let table = self.tables.shift_remove(&Name(key))?;
+
+        self.triggers.retain(|_, t| t.table() != table.name());
+        self.relink_constraints(|column| (column.table() == table.name()).then_some(None));
+
+        Some(table)
+    }
+
+    /// Rename a table keeping its position, triggers and constraints,
+    /// returns the renamed table or None if the table is missing or the new name is taken
+    pub fn rename_table(&mut self, key: &str, name: impl ToString) -> Option<Arc<Table>> {
+        let name = Arc::new(name.to_string());
+        let position = self.tables.get_index_of(&Name(key))?;
+        if self.tables.contains_key(&name) {
+            return None;
+        }
+
+        let (old_name, mut table) = self.tables.shift_remove_index(position)?;
+        Arc::make_mut(&mut table).rename(name.as_str());
+        self.tables
+            .shift_insert(position, name.clone(), table.clone());
+
+        for trigger in self.triggers.values_mut() {
+            if trigger.table() == old_name {
+                Arc::make_mut(trigger).set_table(name.as_str());
+            }
+        }
+        self.relink_constraints(|column| {
+            (column.table() == old_name).then(|| table.column(&column.name()))
+        });
+
+        Some(table)
+    }
+
+    /// Remove a column from a table, dropping constraints that use it
+    pub fn remove_column(&mut self, table: &str, key: &str) -> Option<Arc<Column>> {
+        let column = Arc::make_mut(self.tables.get_mut(&Name(table))?).remove_column(key)?;
+
+        self.relink_constraints(|c| {
+            (c.table() == column.table() && c.name() == column.name()).then_some(None)
+        });
+
+        Some(column)
+    }
+
+    /// Rename a column of a table, pointing constraints at the renamed column
+    pub fn rename_column(
+        &mut self,
+        table: &str,
+        key: &str,
+        name: impl ToString,
+    ) -> Option<Arc<Column>> {
+        let old_column = self.tables.get(&Name(table))?.column(key)?;
+        let column = Arc::make_mut(self.tables.get_mut(&Name(table))?).rename_column(key, name)?;
+
+        self.relink_constraints(|c| {
+            (c.table() == old_column.table() && c.name() == old_column.name())
+                .then(|| Some(column.clone()))
+        });
+
+        Some(column)
+    }
+
+    /// Remove an index from a table
+    pub fn remove_index(&mut self, table: &str, key: &str) -> Option<Index> {
+        Arc::make_mut(self.tables.get_mut(&Name(table))?).remove_index(key)
+    }
+
+    /// Rename an index of a table, returns false if either is missing or the new name is taken
+    pub fn rename_index(&mut self, table: &str, key: &str, name: impl ToString) -> bool {
+        match self.tables.get_mut(&Name(table)) {
+            Some(table) => Arc::make_mut(table).rename_index(key, name),
+            None => false,
+        }
+    }
+
+    /// Add a view to the database
+    pub fn set_view(&mut self, view: View) -> &mut Database {
+        self.views.insert(view.name(), Arc::new(view));
+
+        self
+    }
+
+    /// Get a view by name
+    pub fn view(&self, key: &str) -> Option<Arc<View>> {
+        self.views.get(&Name(key)).cloned()
+    }
+
+    /// Get views iterator
+    pub fn views(&self) -> indexmap::map::Iter<'_, Arc<String>, Arc<View>> {
+        self.views.iter()
+    }
+
+    /// Add a trigger to the database
+    pub fn set_trigger(&mut self, trigger: Trigger) -> &mut Database {
+        self.triggers.insert(trigger.name(), Arc::new(trigger));
+
+        self
+    }
+
+    /// Get a trigger by name
+    pub fn trigger(&self, key: &str) -> Option<Arc<Trigger>> {
+        self.triggers.get(&Name(key)).cloned()
+    }
+
+    /// Get triggers iterator
+    pub fn triggers(&self) -> indexmap::map::Iter<'_, Arc<String>, Arc<Trigger>> {
+        self.triggers.iter()
+    }
+
+    /// Find triggers by table name
+    pub fn triggers_by_table(&self, table_name: &str) -> Vec<Arc<Trigger>> {
+        self.triggers
+            .values()
+            .filter(|t| t.table().as_str() == table_name)
+            .cloned()
+            .collect::<Vec<Arc<Trigger>>>()
+    }
+
+    /// Add a stored procedure or function to the database,
+    /// a procedure and a function may share a name
+    pub fn set_routine(&mut self, routine: Routine) -> &mut Database {
+        self.routines
+            .insert((*routine.kind(), routine.name()), Arc::new(routine));
+
+        self
+    }
+
+    /// Get a stored procedure or function by kind and name
+    pub fn routine(&self, kind: RoutineKind, key: &str) -> Option<Arc<Routine>> {
+        self.routines.get(&RoutineName(kind, key)).cloned()
+    }
+
+    /// Get routines iterator
+    pub fn routines(&self) -> indexmap::map::Iter<'_, RoutineKey, Arc<Routine>> {
+        self.routines.iter()
+    }
+
+    /// Add a scheduled event to the database
+    pub fn set_event(&mut self, event: Event) -> &mut Database {
+        self.events.insert(event.name(), Arc::new(event));
+
+        self
+    }
+
+    /// Get a scheduled event by name
+    pub fn event(&self, key: &str) -> Option<Arc<Event>> {
+        self.events.get(&Name(key)).cloned()
+    }
+
+    /// Get events iterator
+    pub fn events(&self) -> indexmap::map::Iter<'_, Arc<String>, Arc<Event>> {
+        self.events.iter()
+    }
+
     /// Add a constraint to the database
     pub fn set_constraint(&mut self, constraint: Constraint) -> &mut Database {
         self.constraints
@@ -83,16 +348,59 @@ impl Database {
         self
     }
 
+    /// Add a key pair to a constraint by table and column names, creating the constraint
+    /// if needed, returns false when one of the columns is missing
+    pub fn add_constraint_key_pair(
+        &mut self,
+        name: &str,
+        local: (&str, &str),
+        foreign: (&str, &str),
+    ) -> bool {
+        let column = |(table, column): (&str, &str)| self.table(table)?.column(column);
+        let (Some(local), Some(foreign)) = (column(local), column(foreign)) else {
+            return false;
+        };
+
+        let constraint = match self.constraint(name) {
+            Some(constraint) => {
+                let mut constraint = (*constraint).clone();
+                constraint.add_key_pair(local, foreign);
+
+                constraint
+            }
+            None => Constraint::new(name, local, foreign),
+        };
+        self.set_constraint(constraint);
+
+        true
+    }
+
     /// Find a constraint by name
     pub fn constraint(&self, key: &str) -> Option<Arc<Constraint>> {
-        #[allow(clippy::unnecessary_to_owned)]
-        self.constraints.get(&key.to_string()).cloned()
+        self.constraints.get(&Name(key)).cloned()
+    }
+
+    /// Remove a constraint
+    pub fn remove_constraint(&mut self, key: &str) -> Option<Arc<Constraint>> {
+        self.constraints.shift_remove(&Name(key))
+    }
+
+    /// Rename a constraint, returns the renamed constraint or None if it is missing or the new name is taken
+    pub fn rename_constraint(&mut self, key: &str, name: impl ToString) -> Option<Arc<Constraint>> {
+        let name = Arc::new(name.to_string());
+        if self.constraints.contains_key(&name) {
+            return None;
+        }
+
+        let mut constraint = self.constraints.shift_remove(&Name(key))?;
+        Arc::make_mut(&mut constraint).set_name(name.as_str());
+        self.constraints.insert(name, constraint.clone());
+
+        Some(constraint)
     }
 
     /// Get constraints iterator
-    pub fn constraints(
-        &self,
-    ) -> std::collections::hash_map::Iter<'_, Arc<String>, Arc<Constraint>> {
+    pub fn constraints(&self) -> indexmap::map::Iter<'_, Arc<String>, Arc<Constraint>> {
         self.constraints.iter()
     }
 
@@ -109,7 +417,10 @@ impl Database {
                     return true;
                 }
 
-                if c.foreign().table() == table.name() && (side != Some(ConstraintSide::Local)) {
+                if c.foreign().table() == table.name()
+                    && !c.is_cross_database()
+                    && (side != Some(ConstraintSide::Local))
+                {
                     return true;
                 }
 
@@ -132,7 +443,10 @@ impl Database {
                     return true;
                 }
 
-                if c.foreign() == column.as_ref() && (side != Some(ConstraintSide::Local)) {
+                if c.foreign() == column.as_ref()
+                    && !c.is_cross_database()
+                    && (side != Some(ConstraintSide::Local))
+                {
                     return true;
                 }
 
@@ -164,4 +478,181 @@ impl Database {
             .cloned()
             .collect::<Vec<Arc<Constraint>>>()
     }
+
+    /// Find the shortest chains of constraints joining two tables
+    pub fn join_paths(&self, from: &str, to: &str) -> Vec<JoinPath> {
+        JoinPath::find(self, from, to)
+    }
+
+    /// Point indexes and constraints at the column instances held by their tables,
+    /// i.e. after deserialization, foreign columns in other databases are left to `Catalog::relink`
+    pub fn relink(&mut self) -> &mut Database {
+        for table in self.tables.values_mut() {
+            Arc::make_mut(table).relink();
+        }
+
+        let tables = self.tables.clone();
+        self.relink_constraints(|column| {
+            tables
+                .get(&column.table())
+                .and_then(|t| t.column(&column.name()))
+                .map(Some)
+        });
+
+        self
+    }
+
+    /// Check tables, names and constraints for dangling references and mismatches.
+    /// Views reflected as tables are only kept as tables, so a name that is both
+    /// a table and a view is always reported.
+    pub fn validate(&self) -> Result<(), Vec<IntegrityIssue>> {
+        let mut issues = Vec::new();
+
+        for (_, table) in self.tables.iter() {
+            if let Err(table_issues) = table.validate() {
+                issues.extend(table_issues);
+            }
+
+            for (name, _) in table.check_constraints() {
+                if self.constraints.contains_key(name) {
+                    issues.push(IntegrityIssue::DuplicateName {
+                        kind: "constraint",
+                        name: name.clone(),
+                    });
+                }
+            }
+        }
+
+        // the adapter reflects a view either as a table or as a view, never both
+        for (name, _) in self.views.iter() {
+            if self.tables.contains_key(name) {
+                issues.push(IntegrityIssue::DuplicateName {
+                    kind: "table or view",
+                    name: name.clone(),
+                });
+            }
+        }
+
+        let mut constraints = self.constraints.values().collect::<Vec<&Arc<Constraint>>>();
+        constraints.sort_by_key(|c| c.name());
+        for constraint in constraints {
+            for pair in constraint.key_pairs() {
+                let local = self.resolve_constraint_column(constraint, &pair.local, &mut issues);
+                let foreign = if constraint.is_cross_database() {
+                    None
+                } else {
+                    self.resolve_constraint_column(constraint, &pair.foreign, &mut issues)
+                };
+
+                if let (Some(local), Some(foreign)) = (local, foreign) {
+                    if !local.datatype().is_key_compatible(foreign.datatype()) {
+                        issues.push(IntegrityIssue::ConstraintTypeMismatch {
+                            constraint: constraint.name(),
+                            local: format!(
+                                "{}.{} {:?}",
+                                local.table(),
+                                local.name(),
+                                local.datatype()
+                            ),
+                            foreign: format!(
+                                "{}.{} {:?}",
+                                foreign.table(),
+                                foreign.name(),
+                                foreign.datatype()
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Find the column a constraint refers to, recording an issue when it is missing
+    fn resolve_constraint_column(
+        &self,
+        constraint: &Constraint,
+        column: &Column,
+        issues: &mut Vec<IntegrityIssue>,
+    ) -> Option<Arc<Column>> {
+        let resolved = self
+            .table(&column.table())
+            .and_then(|t| t.column(&column.name()));
+
+        if resolved.is_none() {
+            issues.push(IntegrityIssue::DanglingConstraint {
+                constraint: constraint.name(),
+                table: column.table(),
+                column: column.name(),
+            });
+        }
+
+        resolved
+    }
+
+    /// Swap constraint columns through `resolve`, which returns None to keep a column,
+    /// Some(None) to drop the whole constraint, or Some(Some(column)) to replace it
+    fn relink_constraints(&mut self, resolve: impl Fn(&Column) -> Option<Option<Arc<Column>>>) {
+        self.constraints.retain(|_, constraint| {
+            let cross_database = constraint.is_cross_database();
+            let constraint = Arc::make_mut(constraint);
+
+            for pair in constraint.key_pairs_mut() {
+                if let Some(local) = resolve(&pair.local) {
+                    match local {
+                        Some(local) => pair.local = local,
+                        None => return false,
+                    }
+                }
+
+                if cross_database {
+                    continue;
+                }
+
+                if let Some(foreign) = resolve(&pair.foreign) {
+                    match foreign {
+                        Some(foreign) => pair.foreign = foreign,
+                        None => return false,
+                    }
+                }
+            }
+
+            true
+        });
+    }
+}
+
+/// Routines are keyed by kind and name, which JSON can't use as a map key
+fn serialize_routines<S: Serializer>(
+    routines: &IndexMap<RoutineKey, Arc<Routine>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(routines.values())
+}
+
+/// Read routines as a list, or as a map by name as written before they were keyed by kind
+fn deserialize_routines<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<IndexMap<RoutineKey, Arc<Routine>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Routines {
+        List(Vec<Routine>),
+        ByName(IndexMap<String, Routine>),
+    }
+
+    let routines = match Routines::deserialize(deserializer)? {
+        Routines::List(routines) => routines,
+        Routines::ByName(routines) => routines.into_values().collect(),
+    };
+
+    Ok(routines
+        .into_iter()
+        .map(|r| ((*r.kind(), r.name()), Arc::new(r)))
+        .collect())
 }
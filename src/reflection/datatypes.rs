@@ -57,6 +57,27 @@ impl SqlDatatype {
         }
     }
 
+    /// Turn a numeric datatype unsigned, other datatypes are returned unchanged
+    pub fn unsigned(self) -> SqlDatatype {
+        match self {
+            SqlDatatype::Tinyint(len, _) => SqlDatatype::Tinyint(len, SqlSigned::Unsigned),
+            SqlDatatype::Smallint(len, _) => SqlDatatype::Smallint(len, SqlSigned::Unsigned),
+            SqlDatatype::Mediumint(len, _) => SqlDatatype::Mediumint(len, SqlSigned::Unsigned),
+            SqlDatatype::Int(len, _) => SqlDatatype::Int(len, SqlSigned::Unsigned),
+            SqlDatatype::Bigint(len, _) => SqlDatatype::Bigint(len, SqlSigned::Unsigned),
+            SqlDatatype::Float(len, scale, _) => {
+                SqlDatatype::Float(len, scale, SqlSigned::Unsigned)
+            }
+            SqlDatatype::Double(len, scale, _) => {
+                SqlDatatype::Double(len, scale, SqlSigned::Unsigned)
+            }
+            SqlDatatype::Decimal(len, scale, _) => {
+                SqlDatatype::Decimal(len, scale, SqlSigned::Unsigned)
+            }
+            other => other,
+        }
+    }
+
     /// Get datatype length
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> Option<u32> {
@@ -87,6 +108,26 @@ impl SqlDatatype {
             SqlDatatype::Text(_) | SqlDatatype::Varchar(_) | SqlDatatype::Char(_)
         )
     }
+
+    /// Check if two datatypes can be paired in a foreign key, i.e. integers of the same size and sign,
+    /// equal decimals, or strings of the same family regardless of length
+    pub fn is_key_compatible(&self, other: &SqlDatatype) -> bool {
+        match (self, other) {
+            (SqlDatatype::Decimal(..), SqlDatatype::Decimal(..)) => self == other,
+            (
+                SqlDatatype::Char(_) | SqlDatatype::Varchar(_),
+                SqlDatatype::Char(_) | SqlDatatype::Varchar(_),
+            ) => true,
+            (
+                SqlDatatype::Binary(_) | SqlDatatype::Varbinary(_),
+                SqlDatatype::Binary(_) | SqlDatatype::Varbinary(_),
+            ) => true,
+            _ => {
+                std::mem::discriminant(self) == std::mem::discriminant(other)
+                    && self.sign() == other.sign()
+            }
+        }
+    }
 }
 
 impl Default for SqlDatatype {
@@ -107,7 +148,7 @@ impl TryFrom<&str> for SqlDatatype {
     type Error = ParseDatatypeError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        return if value.contains('(') && value.contains(')') {
+        if value.contains('(') && value.contains(')') {
             // type with length information
 
             let is_signed = if value.contains("unsigned") {
@@ -129,11 +170,11 @@ impl TryFrom<&str> for SqlDatatype {
                         .map(|s| s.trim_matches(trim_match).to_string())
                         .collect();
 
-                    return if type_group == "set" {
+                    if type_group == "set" {
                         Ok(SqlDatatype::Set(options))
                     } else {
                         Ok(SqlDatatype::Enum(options))
-                    };
+                    }
                 }
                 "float" | "double" | "decimal" => {
                     let (left, right) = {
@@ -180,9 +221,27 @@ impl TryFrom<&str> for SqlDatatype {
                 }
             }
         } else {
-            // fixed length type
+            // fixed length type, MySQL 8 also reports integers without display width
+
+            let is_signed = if value.contains("unsigned") {
+                SqlSigned::Unsigned
+            } else {
+                SqlSigned::Signed
+            };
+
+            // default display width as reported by MariaDB
+            let width = |signed: u32, unsigned: u32| match is_signed {
+                SqlSigned::Signed => signed,
+                SqlSigned::Unsigned => unsigned,
+            };
 
-            match value {
+            match value.split_whitespace().next().unwrap_or_default() {
+                "tinyint" => Ok(SqlDatatype::Tinyint(width(4, 3), is_signed)),
+                "smallint" => Ok(SqlDatatype::Smallint(width(6, 5), is_signed)),
+                "mediumint" => Ok(SqlDatatype::Mediumint(width(9, 8), is_signed)),
+                "int" => Ok(SqlDatatype::Int(width(11, 10), is_signed)),
+                "bigint" => Ok(SqlDatatype::Bigint(20, is_signed)),
+                _ if value.contains(' ') => Err(ParseDatatypeError),
                 "text" => Ok(SqlDatatype::Text(65535)),
                 "date" => Ok(SqlDatatype::Date),
                 "time" => Ok(SqlDatatype::Time),
@@ -190,7 +249,7 @@ impl TryFrom<&str> for SqlDatatype {
                 "timestamp" => Ok(SqlDatatype::Timestamp),
                 _ => Err(ParseDatatypeError),
             }
-        };
+        }
     }
 }
 
@@ -201,6 +260,59 @@ pub enum DefaultValue {
     #[default]
     Null,
     Value(Value),
+    /// Function or expression evaluated on insert, i.e. current_timestamp() or (json_array())
+    Expression(String),
+}
+
+impl DefaultValue {
+    /// Parse a column default as reported by information_schema.COLUMNS.COLUMN_DEFAULT,
+    /// where string literals are quoted, NULL is unquoted and anything else is an expression
+    pub fn from_sql(value: &str, datatype: &SqlDatatype) -> DefaultValue {
+        let value = value.trim();
+
+        if value.eq_ignore_ascii_case("NULL") {
+            return DefaultValue::Null;
+        }
+
+        let literal = if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+            value[1..value.len() - 1].replace("''", "'")
+        } else if let Some(number) = DefaultValue::typed_number(value, datatype) {
+            return DefaultValue::Value(number);
+        } else {
+            return DefaultValue::Expression(value.to_string());
+        };
+
+        DefaultValue::Value(
+            DefaultValue::typed_number(&literal, datatype).unwrap_or(Value::String(literal)),
+        )
+    }
+
+    /// Booleans follow the Rust type mapping, decimals stay text to keep their precision
+    fn typed_number(value: &str, datatype: &SqlDatatype) -> Option<Value> {
+        match datatype {
+            SqlDatatype::Tinyint(1, _) => value.parse::<i64>().ok().map(|v| Value::Bool(v != 0)),
+            SqlDatatype::Tinyint(_, SqlSigned::Unsigned)
+            | SqlDatatype::Smallint(_, SqlSigned::Unsigned)
+            | SqlDatatype::Mediumint(_, SqlSigned::Unsigned)
+            | SqlDatatype::Int(_, SqlSigned::Unsigned)
+            | SqlDatatype::Bigint(_, SqlSigned::Unsigned) => {
+                value.parse::<u64>().ok().map(Value::from)
+            }
+            SqlDatatype::Tinyint(_, _)
+            | SqlDatatype::Smallint(_, _)
+            | SqlDatatype::Mediumint(_, _)
+            | SqlDatatype::Int(_, _)
+            | SqlDatatype::Bigint(_, _) => value.parse::<i64>().ok().map(Value::from),
+            SqlDatatype::Float(_, _, _) | SqlDatatype::Double(_, _, _) => {
+                value.parse::<f64>().ok().map(Value::from)
+            }
+            SqlDatatype::Decimal(_, _, _) => value
+                .parse::<f64>()
+                .ok()
+                .map(|_| Value::String(value.to_string())),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    name: Arc<String>,
+    definition: String,
+    event_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execute_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interval_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interval_field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    starts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ends: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_completion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+impl Event {
+    /// Create a scheduled event with its body and type (ONE TIME or RECURRING)
+    pub fn new(name: impl ToString, definition: impl ToString, event_type: impl ToString) -> Event {
+        Event {
+            name: Arc::new(name.to_string()),
+            definition: definition.to_string(),
+            event_type: event_type.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Get event name
+    pub fn name(&self) -> Arc<String> {
+        self.name.clone()
+    }
+
+    /// Get event body
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+
+    /// Get event type, i.e. ONE TIME or RECURRING
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// Set execution time of a one time event
+    pub fn set_execute_at(&mut self, execute_at: Option<impl ToString>) -> &mut Event {
+        self.execute_at = execute_at.map(|e| e.to_string());
+        self
+    }
+
+    /// Get execution time of a one time event if available
+    pub fn execute_at(&self) -> Option<&str> {
+        self.execute_at.as_deref()
+    }
+
+    /// Set recurring event interval, i.e. value 1 and field DAY
+    pub fn set_interval(
+        &mut self,
+        value: Option<impl ToString>,
+        field: Option<impl ToString>,
+    ) -> &mut Event {
+        self.interval_value = value.map(|v| v.to_string());
+        self.interval_field = field.map(|f| f.to_string());
+        self
+    }
+
+    /// Get recurring event interval value if available
+    pub fn interval_value(&self) -> Option<&str> {
+        self.interval_value.as_deref()
+    }
+
+    /// Get recurring event interval field if available
+    pub fn interval_field(&self) -> Option<&str> {
+        self.interval_field.as_deref()
+    }
+
+    /// Set recurring event schedule boundaries
+    pub fn set_schedule(
+        &mut self,
+        starts: Option<impl ToString>,
+        ends: Option<impl ToString>,
+    ) -> &mut Event {
+        self.starts = starts.map(|s| s.to_string());
+        self.ends = ends.map(|e| e.to_string());
+        self
+    }
+
+    /// Get recurring event start time if available
+    pub fn starts(&self) -> Option<&str> {
+        self.starts.as_deref()
+    }
+
+    /// Get recurring event end time if available
+    pub fn ends(&self) -> Option<&str> {
+        self.ends.as_deref()
+    }
+
+    /// Set event status, i.e. ENABLED or DISABLED
+    pub fn set_status(&mut self, status: Option<impl ToString>) -> &mut Event {
+        self.status = status.map(|s| s.to_string());
+        self
+    }
+
+    /// Get event status if available
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Set ON COMPLETION behaviour, i.e. PRESERVE or NOT PRESERVE
+    pub fn set_on_completion(&mut self, on_completion: Option<impl ToString>) -> &mut Event {
+        self.on_completion = on_completion.map(|o| o.to_string());
+        self
+    }
+
+    /// Get ON COMPLETION behaviour if available
+    pub fn on_completion(&self) -> Option<&str> {
+        self.on_completion.as_deref()
+    }
+
+    /// Set event definer, i.e. `user`@`host`
+    pub fn set_definer(&mut self, definer: Option<impl ToString>) -> &mut Event {
+        self.definer = definer.map(|d| d.to_string());
+        self
+    }
+
+    /// Get event definer if available
+    pub fn definer(&self) -> Option<&str> {
+        self.definer.as_deref()
+    }
+
+    /// Set event comment
+    pub fn set_comment(&mut self, comment: Option<impl ToString>) -> &mut Event {
+        self.comment = comment.map(|c| c.to_string());
+        self
+    }
+
+    /// Get event comment if available
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
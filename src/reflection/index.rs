@@ -8,6 +8,12 @@ pub struct Index {
     column: Arc<Column>,
     primary: bool,
     unique: bool,
+    #[serde(default = "single_column")]
+    column_count: usize,
+}
+
+fn single_column() -> usize {
+    1
 }
 
 impl Index {
@@ -18,15 +24,34 @@ impl Index {
             column,
             primary,
             unique,
+            column_count: 1,
         }
     }
 
+    /// Set the number of columns covered by the index
+    pub fn set_column_count(&mut self, column_count: usize) -> &mut Index {
+        self.column_count = column_count.max(1);
+        self
+    }
+
+    /// Set index name
+    pub(crate) fn set_name(&mut self, name: impl ToString) -> &mut Index {
+        self.name = Arc::new(name.to_string());
+        self
+    }
+
+    /// Point the index at another column instance
+    pub(crate) fn set_column(&mut self, column: Arc<Column>) -> &mut Index {
+        self.column = column;
+        self
+    }
+
     /// Get index name
     pub fn name(&self) -> Arc<String> {
         self.name.clone()
     }
 
-    /// Get column
+    /// Get the first column of the index
     pub fn column(&self) -> &Column {
         &self.column
     }
@@ -40,4 +65,9 @@ impl Index {
     pub fn unique(&self) -> bool {
         self.unique
     }
+
+    /// Get the number of columns covered by the index, the column being the first of them
+    pub fn column_count(&self) -> usize {
+        self.column_count
+    }
 }
@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Inconsistency found while validating a table or database
+#[derive(Clone, Debug, PartialEq)]
+pub enum IntegrityIssue {
+    /// Column stored in a table other than the one it names
+    ColumnTableMismatch {
+        table: Arc<String>,
+        column: Arc<String>,
+        column_table: Arc<String>,
+    },
+    /// Primary key entry without a matching column
+    MissingPrimaryKeyColumn {
+        table: Arc<String>,
+        column: Arc<String>,
+    },
+    /// Index on a column that is not in the table
+    DanglingIndex {
+        table: Arc<String>,
+        index: Arc<String>,
+        column: Arc<String>,
+    },
+    /// Check constraint bound to a missing table or column
+    DanglingCheckConstraint {
+        table: Arc<String>,
+        constraint: Arc<String>,
+        column: Arc<String>,
+    },
+    /// Foreign key column pointing at a missing table or column
+    DanglingConstraint {
+        constraint: Arc<String>,
+        table: Arc<String>,
+        column: Arc<String>,
+    },
+    /// Foreign key pairing columns of incompatible types
+    ConstraintTypeMismatch {
+        constraint: Arc<String>,
+        local: String,
+        foreign: String,
+    },
+    /// Name used more than once within the same namespace
+    DuplicateName {
+        kind: &'static str,
+        name: Arc<String>,
+    },
+}
+
+impl Display for IntegrityIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegrityIssue::ColumnTableMismatch {
+                table,
+                column,
+                column_table,
+            } => write!(
+                f,
+                "column {}.{} belongs to table {}",
+                table, column, column_table
+            ),
+            IntegrityIssue::MissingPrimaryKeyColumn { table, column } => {
+                write!(
+                    f,
+                    "primary key of {} references missing column {}",
+                    table, column
+                )
+            }
+            IntegrityIssue::DanglingIndex {
+                table,
+                index,
+                column,
+            } => write!(
+                f,
+                "index {} on {} references missing column {}",
+                index, table, column
+            ),
+            IntegrityIssue::DanglingCheckConstraint {
+                table,
+                constraint,
+                column,
+            } => write!(
+                f,
+                "check constraint {} references missing column {}.{}",
+                constraint, table, column
+            ),
+            IntegrityIssue::DanglingConstraint {
+                constraint,
+                table,
+                column,
+            } => write!(
+                f,
+                "constraint {} references missing column {}.{}",
+                constraint, table, column
+            ),
+            IntegrityIssue::ConstraintTypeMismatch {
+                constraint,
+                local,
+                foreign,
+            } => write!(
+                f,
+                "constraint {} pairs {} with incompatible {}",
+                constraint, local, foreign
+            ),
+            IntegrityIssue::DuplicateName { kind, name } => {
+                write!(f, "duplicate {} name {}", kind, name)
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::reflection::routine::RoutineKind;
+use indexmap::Equivalent;
+use std::sync::Arc;
+
+/// Borrowed name, looks up `Arc<String>` keys without allocating
+#[derive(Hash)]
+pub(crate) struct Name<'a>(pub(crate) &'a str);
+
+impl Equivalent<Arc<String>> for Name<'_> {
+    fn equivalent(&self, key: &Arc<String>) -> bool {
+        self.0 == key.as_str()
+    }
+}
+
+/// Borrowed routine kind and name, hashes like the `(RoutineKind, Arc<String>)` key
+#[derive(Hash)]
+pub(crate) struct RoutineName<'a>(pub(crate) RoutineKind, pub(crate) &'a str);
+
+impl Equivalent<(RoutineKind, Arc<String>)> for RoutineName<'_> {
+    fn equivalent(&self, key: &(RoutineKind, Arc<String>)) -> bool {
+        self.0 == key.0 && self.1 == key.1.as_str()
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::slice::Iter;
+use std::sync::Arc;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Partition {
+    name: Arc<String>,
+    method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expression: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subpartition_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subpartition_expression: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    subpartitions: Vec<Arc<String>>,
+}
+
+impl Partition {
+    /// Create a partition with a name and partitioning method (RANGE, LIST, HASH, KEY...)
+    pub fn new(name: impl ToString, method: impl ToString) -> Partition {
+        Partition {
+            name: Arc::new(name.to_string()),
+            method: method.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Set partitioning expression
+    pub fn set_expression(&mut self, expression: Option<impl ToString>) -> &mut Partition {
+        self.expression = expression.map(|e| e.to_string());
+        self
+    }
+
+    /// Set partition description, i.e. the VALUES LESS THAN or VALUES IN value list
+    pub fn set_description(&mut self, description: Option<impl ToString>) -> &mut Partition {
+        self.description = description.map(|d| d.to_string());
+        self
+    }
+
+    /// Set subpartitioning method and expression
+    pub fn set_subpartitioning(
+        &mut self,
+        method: Option<impl ToString>,
+        expression: Option<impl ToString>,
+    ) -> &mut Partition {
+        self.subpartition_method = method.map(|m| m.to_string());
+        self.subpartition_expression = expression.map(|e| e.to_string());
+        self
+    }
+
+    /// Add a subpartition by name
+    pub fn add_subpartition(&mut self, name: impl ToString) -> &mut Partition {
+        let name = Arc::new(name.to_string());
+        if !self.subpartitions.contains(&name) {
+            self.subpartitions.push(name);
+        }
+
+        self
+    }
+
+    /// Get partition name
+    pub fn name(&self) -> Arc<String> {
+        self.name.clone()
+    }
+
+    /// Get partitioning method
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Get partitioning expression if available
+    pub fn expression(&self) -> Option<&str> {
+        self.expression.as_deref()
+    }
+
+    /// Get partition description if available
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Get subpartitioning method if available
+    pub fn subpartition_method(&self) -> Option<&str> {
+        self.subpartition_method.as_deref()
+    }
+
+    /// Get subpartitioning expression if available
+    pub fn subpartition_expression(&self) -> Option<&str> {
+        self.subpartition_expression.as_deref()
+    }
+
+    /// Get subpartition names iterator
+    pub fn subpartitions(&self) -> Iter<'_, Arc<String>> {
+        self.subpartitions.iter()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::reflection::datatypes::SqlDatatype;
+use serde::{Deserialize, Serialize};
+use std::slice::Iter;
+use std::sync::Arc;
+
+#[derive(
+    Clone, Copy, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+/// Stored routine kind
+pub enum RoutineKind {
+    #[default]
+    Procedure,
+    Function,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// Stored procedure parameter direction
+pub enum ParameterMode {
+    #[default]
+    In,
+    Out,
+    InOut,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoutineParameter {
+    pub name: Arc<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<ParameterMode>,
+    /// Parsed type, `None` when the declared type is not understood
+    pub datatype: Option<SqlDatatype>,
+    /// Type as declared in the routine, i.e. `json` or `varchar(64) charset latin1`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declared_type: Option<String>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Routine {
+    name: Arc<String>,
+    kind: RoutineKind,
+    parameters: Vec<RoutineParameter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    returns: Option<SqlDatatype>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    declared_returns: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definition: Option<String>,
+    deterministic: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_access: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    security_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+impl Routine {
+    /// Create a stored procedure or function
+    pub fn new(name: impl ToString, kind: RoutineKind) -> Routine {
+        Routine {
+            name: Arc::new(name.to_string()),
+            kind,
+            ..Default::default()
+        }
+    }
+
+    /// Get routine name
+    pub fn name(&self) -> Arc<String> {
+        self.name.clone()
+    }
+
+    /// Get routine kind
+    pub fn kind(&self) -> &RoutineKind {
+        &self.kind
+    }
+
+    /// Add a parameter, in order of declaration
+    pub fn add_parameter(
+        &mut self,
+        name: impl ToString,
+        mode: Option<ParameterMode>,
+        datatype: SqlDatatype,
+    ) -> &mut Routine {
+        self.parameters.push(RoutineParameter {
+            name: Arc::new(name.to_string()),
+            mode,
+            datatype: Some(datatype),
+            declared_type: None,
+        });
+
+        self
+    }
+
+    /// Add a parameter by its declared type, keeping the text when it can't be parsed
+    pub fn add_declared_parameter(
+        &mut self,
+        name: impl ToString,
+        mode: Option<ParameterMode>,
+        declared_type: impl ToString,
+    ) -> &mut Routine {
+        let declared_type = declared_type.to_string();
+
+        self.parameters.push(RoutineParameter {
+            name: Arc::new(name.to_string()),
+            mode,
+            datatype: SqlDatatype::try_from(declared_type.as_str()).ok(),
+            declared_type: Some(declared_type),
+        });
+
+        self
+    }
+
+    /// Get parameters iterator
+    pub fn parameters(&self) -> Iter<'_, RoutineParameter> {
+        self.parameters.iter()
+    }
+
+    /// Set function return type
+    pub fn set_returns(&mut self, returns: Option<SqlDatatype>) -> &mut Routine {
+        self.returns = returns;
+        self
+    }
+
+    /// Get function return type if available
+    pub fn returns(&self) -> Option<&SqlDatatype> {
+        self.returns.as_ref()
+    }
+
+    /// Set function return type by its declared type, keeping the text when it can't be parsed
+    pub fn set_declared_returns(
+        &mut self,
+        declared_returns: Option<impl ToString>,
+    ) -> &mut Routine {
+        self.declared_returns = declared_returns.map(|r| r.to_string());
+        self.returns = self
+            .declared_returns
+            .as_deref()
+            .and_then(|r| SqlDatatype::try_from(r).ok());
+        self
+    }
+
+    /// Get function return type as declared, if reflected
+    pub fn declared_returns(&self) -> Option<&str> {
+        self.declared_returns.as_deref()
+    }
+
+    /// Set routine body
+    pub fn set_definition(&mut self, definition: Option<impl ToString>) -> &mut Routine {
+        self.definition = definition.map(|d| d.to_string());
+        self
+    }
+
+    /// Get routine body if available
+    pub fn definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    /// Set DETERMINISTIC flag
+    pub fn set_deterministic(&mut self, deterministic: bool) -> &mut Routine {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Get DETERMINISTIC flag
+    pub fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Set SQL data access characteristic, i.e. CONTAINS SQL, READS SQL DATA...
+    pub fn set_data_access(&mut self, data_access: Option<impl ToString>) -> &mut Routine {
+        self.data_access = data_access.map(|d| d.to_string());
+        self
+    }
+
+    /// Get SQL data access characteristic if available
+    pub fn data_access(&self) -> Option<&str> {
+        self.data_access.as_deref()
+    }
+
+    /// Set SQL SECURITY type (DEFINER or INVOKER)
+    pub fn set_security_type(&mut self, security_type: Option<impl ToString>) -> &mut Routine {
+        self.security_type = security_type.map(|s| s.to_string());
+        self
+    }
+
+    /// Get SQL SECURITY type if available
+    pub fn security_type(&self) -> Option<&str> {
+        self.security_type.as_deref()
+    }
+
+    /// Set routine definer, i.e. `user`@`host`
+    pub fn set_definer(&mut self, definer: Option<impl ToString>) -> &mut Routine {
+        self.definer = definer.map(|d| d.to_string());
+        self
+    }
+
+    /// Get routine definer if available
+    pub fn definer(&self) -> Option<&str> {
+        self.definer.as_deref()
+    }
+
+    /// Set routine comment
+    pub fn set_comment(&mut self, comment: Option<impl ToString>) -> &mut Routine {
+        self.comment = comment.map(|c| c.to_string());
+        self
+    }
+
+    /// Get routine comment if available
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
@@ -1,20 +1,58 @@
-use crate::metadata::consts::{METADATA_CHARSET, METADATA_COLLATION, METADATA_FLAG_PRIMARY};
+use crate::metadata::consts::{METADATA_CHARSET, METADATA_COLLATION};
 use crate::metadata::WithMetadata;
+use crate::metadata::{absorb_typed_metadata, meta_text_value, serialize_sorted};
+use crate::reflection::check_constraint::CheckConstraint;
 use crate::reflection::column::Column;
 use crate::reflection::index::Index;
+use crate::reflection::integrity::IntegrityIssue;
+use crate::reflection::name::Name;
+use crate::reflection::partition::Partition;
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::slice::Iter;
 use std::sync::Arc;
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Table {
     name: Arc<String>,
     primary_key: Vec<Arc<String>>,
     columns: IndexMap<Arc<String>, Arc<Column>>,
     indexes: IndexMap<Arc<String>, Index>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    check_constraints: IndexMap<Arc<String>, CheckConstraint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    engine: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    row_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_increment: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    create_options: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    update_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checksum: Option<String>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    partitions: IndexMap<Arc<String>, Partition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<String>,
+    #[serde(serialize_with = "serialize_sorted")]
     metadata: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    metadata_values: HashMap<String, Value>,
 }
 
 impl WithMetadata for Table {
@@ -27,6 +65,54 @@ impl WithMetadata for Table {
     fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
         &mut self.metadata
     }
+
+    /// Borrow structured metadata container for reading
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    /// Borrow structured metadata container for writing
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
+
+    /// Read charset and collation through their metadata keys
+    fn typed_meta(&self, key: &str) -> Option<Option<String>> {
+        match key {
+            METADATA_CHARSET => Some(self.charset.clone()),
+            METADATA_COLLATION => Some(self.collation.clone()),
+            _ => None,
+        }
+    }
+
+    /// Write charset and collation through their metadata keys
+    fn set_typed_meta(&mut self, key: &str, value: &Value) -> bool {
+        match key {
+            METADATA_CHARSET => self.charset = meta_text_value(value),
+            METADATA_COLLATION => self.collation = meta_text_value(value),
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl Serialize for Table {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Table::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Table {
+    /// Deserialize and relink, so shared columns are shared again, moving well known
+    /// metadata keys of older output into their typed fields
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut table = Table::deserialize(deserializer)?;
+        absorb_typed_metadata(&mut table);
+        table.relink();
+
+        Ok(table)
+    }
 }
 
 impl Table {
@@ -43,26 +129,178 @@ impl Table {
         self.name.clone()
     }
 
+    /// Rename the table, updating the table name on its columns, indexes and check constraints
+    pub fn rename(&mut self, name: impl ToString) -> &mut Table {
+        self.name = Arc::new(name.to_string());
+
+        for column in self.columns.values_mut() {
+            Arc::make_mut(column).set_table(self.name.as_str());
+        }
+
+        for index in self.indexes.values_mut() {
+            if let Some(column) = self.columns.get(&index.column().name()) {
+                index.set_column(column.clone());
+            }
+        }
+
+        for check_constraint in self.check_constraints.values_mut() {
+            check_constraint.set_table(self.name.as_str());
+        }
+
+        self
+    }
+
+    /// Set an optional table comment
+    pub fn set_comment(&mut self, comment: Option<impl ToString>) -> &mut Table {
+        self.comment = comment.map(|c| c.to_string());
+        self
+    }
+
+    /// Get table comment if available
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Set storage engine
+    pub fn set_engine(&mut self, engine: Option<impl ToString>) -> &mut Table {
+        self.engine = engine.map(|e| e.to_string());
+        self
+    }
+
+    /// Get storage engine if available
+    pub fn engine(&self) -> Option<&str> {
+        self.engine.as_deref()
+    }
+
+    /// Set row format
+    pub fn set_row_format(&mut self, row_format: Option<impl ToString>) -> &mut Table {
+        self.row_format = row_format.map(|r| r.to_string());
+        self
+    }
+
+    /// Get row format if available
+    pub fn row_format(&self) -> Option<&str> {
+        self.row_format.as_deref()
+    }
+
+    /// Set next AUTO_INCREMENT value
+    pub fn set_auto_increment(&mut self, auto_increment: Option<u64>) -> &mut Table {
+        self.auto_increment = auto_increment;
+        self
+    }
+
+    /// Get next AUTO_INCREMENT value if available
+    pub fn auto_increment(&self) -> Option<u64> {
+        self.auto_increment
+    }
+
+    /// Set extra CREATE TABLE options
+    pub fn set_create_options(&mut self, create_options: Option<impl ToString>) -> &mut Table {
+        self.create_options = create_options.map(|c| c.to_string());
+        self
+    }
+
+    /// Get extra CREATE TABLE options if available
+    pub fn create_options(&self) -> Option<&str> {
+        self.create_options.as_deref()
+    }
+
+    /// Set time the table was created, as reported by the server
+    pub fn set_create_time(&mut self, create_time: Option<impl ToString>) -> &mut Table {
+        self.create_time = create_time.map(|c| c.to_string());
+        self
+    }
+
+    /// Get table creation time if available
+    pub fn create_time(&self) -> Option<&str> {
+        self.create_time.as_deref()
+    }
+
+    /// Set time the table was last updated, as reported by the server
+    pub fn set_update_time(&mut self, update_time: Option<impl ToString>) -> &mut Table {
+        self.update_time = update_time.map(|u| u.to_string());
+        self
+    }
+
+    /// Get table last update time if available
+    pub fn update_time(&self) -> Option<&str> {
+        self.update_time.as_deref()
+    }
+
+    /// Set checksum of the table definition
+    pub fn set_checksum(&mut self, checksum: Option<impl ToString>) -> &mut Table {
+        self.checksum = checksum.map(|c| c.to_string());
+        self
+    }
+
+    /// Get checksum of the table definition if available
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+
+    /// Check if the table has any of create time, update time or checksum to compare against
+    pub fn has_change_stamp(&self) -> bool {
+        self.create_time.is_some() || self.update_time.is_some() || self.checksum.is_some()
+    }
+
+    /// Set default character set
+    pub fn set_charset(&mut self, charset: Option<impl ToString>) -> &mut Table {
+        self.charset = charset.map(|c| c.to_string());
+        self
+    }
+
+    /// Get default character set if available
+    pub fn charset(&self) -> Option<&str> {
+        self.charset.as_deref()
+    }
+
+    /// Set default collation
+    pub fn set_collation(&mut self, collation: Option<impl ToString>) -> &mut Table {
+        self.collation = collation.map(|c| c.to_string());
+        self
+    }
+
+    /// Get default collation if available
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
+    /// Add a partition definition
+    pub fn set_partition(&mut self, partition: Partition) -> &mut Table {
+        self.partitions.insert(partition.name(), partition);
+
+        self
+    }
+
+    /// Find a partition by name
+    pub fn partition(&self, key: &str) -> Option<&Partition> {
+        self.partitions.get(&Name(key))
+    }
+
+    /// Get partitions iterator
+    pub fn partitions(&self) -> indexmap::map::Iter<'_, Arc<String>, Partition> {
+        self.partitions.iter()
+    }
+
+    /// Check whether the table is partitioned
+    pub fn is_partitioned(&self) -> bool {
+        !self.partitions.is_empty()
+    }
+
     /// Add a new column to the table
     pub fn set_column(&mut self, mut column: Column) -> &mut Table {
         if column.datatype().is_text()
-            && column.meta(METADATA_CHARSET).is_none()
-            && column.meta(METADATA_COLLATION).is_none()
-            && self.meta(METADATA_CHARSET).is_some()
-            && self.meta(METADATA_COLLATION).is_some()
+            && column.charset().is_none()
+            && column.collation().is_none()
+            && self.charset.is_some()
+            && self.collation.is_some()
         {
             column
-                .set_meta(
-                    METADATA_CHARSET,
-                    self.meta(METADATA_CHARSET).unwrap_or_default(),
-                )
-                .set_meta(
-                    METADATA_COLLATION,
-                    self.meta(METADATA_COLLATION).unwrap_or_default(),
-                );
+                .set_charset(self.charset.as_deref())
+                .set_collation(self.collation.as_deref());
         }
 
-        if column.meta_flag(METADATA_FLAG_PRIMARY) && !self.primary_key.contains(&column.name()) {
+        if column.primary() && !self.primary_key.contains(&column.name()) {
             self.primary_key.push(column.name());
         }
 
@@ -73,7 +311,12 @@ impl Table {
 
     /// Find a column by name
     pub fn column(&self, key: &str) -> Option<Arc<Column>> {
-        self.columns.get(&key.to_string()).cloned()
+        self.columns.get(&Name(key)).cloned()
+    }
+
+    /// Borrow a column for writing, indexes and constraints keep the previous instance until relinked
+    pub(crate) fn column_mut(&mut self, key: &str) -> Option<&mut Column> {
+        self.columns.get_mut(&Name(key)).map(Arc::make_mut)
     }
 
     /// Get columns iterator
@@ -81,6 +324,70 @@ impl Table {
         self.columns.iter()
     }
 
+    /// Remove a column along with its primary key entry, indexes and column level check constraints
+    pub fn remove_column(&mut self, key: &str) -> Option<Arc<Column>> {
+        let column = self.columns.shift_remove(&Name(key))?;
+
+        self.primary_key.retain(|k| *k != column.name());
+        self.indexes
+            .retain(|_, i| i.column().name() != column.name());
+        self.check_constraints
+            .retain(|_, c| c.column() != Some(column.name()));
+
+        Some(column)
+    }
+
+    /// Rename a column keeping its position, primary key entry, indexes and check constraints,
+    /// returns the renamed column or None if the column is missing or the new name is taken.
+    /// Column level checks named after the column are renamed along, and backtick quoted
+    /// references in check expressions are rewritten. Unquoted references are left as they are.
+    pub fn rename_column(&mut self, key: &str, name: impl ToString) -> Option<Arc<Column>> {
+        let name = Arc::new(name.to_string());
+        let position = self.columns.get_index_of(&Name(key))?;
+        if self.columns.contains_key(&name) {
+            return None;
+        }
+
+        let (old_name, mut column) = self.columns.shift_remove_index(position)?;
+        Arc::make_mut(&mut column).set_name(name.as_str());
+        self.columns
+            .shift_insert(position, name.clone(), column.clone());
+
+        for k in self.primary_key.iter_mut() {
+            if *k == old_name {
+                *k = name.clone();
+            }
+        }
+
+        for index in self.indexes.values_mut() {
+            if index.column().name() == old_name {
+                index.set_column(column.clone());
+            }
+        }
+
+        let check_name_taken = self.check_constraints.contains_key(&name);
+        let (quoted_old, quoted_new) = (format!("`{}`", old_name), format!("`{}`", name));
+        for (mut check_name, mut check_constraint) in std::mem::take(&mut self.check_constraints) {
+            if check_constraint.column() == Some(old_name.clone()) {
+                check_constraint.set_column(Some(name.as_str()));
+
+                if check_name == old_name && !check_name_taken {
+                    check_constraint.set_name(name.as_str());
+                    check_name = name.clone();
+                }
+            }
+
+            let expression = check_constraint
+                .expression()
+                .replace(&quoted_old, &quoted_new);
+            check_constraint.set_expression(expression);
+
+            self.check_constraints.insert(check_name, check_constraint);
+        }
+
+        Some(column)
+    }
+
     /// Add a new index
     pub fn set_index(&mut self, index: Index) -> &mut Table {
         if index.primary() && !self.primary_key.contains(&index.column().name()) {
@@ -92,9 +399,37 @@ impl Table {
         self
     }
 
+    /// Add an index column in key order, as listed by `SHOW INDEXES`,
+    /// the first column defines the index and further ones widen it
+    pub fn add_index_column(
+        &mut self,
+        name: impl ToString,
+        column: Arc<Column>,
+        sequence: usize,
+        primary: bool,
+        unique: bool,
+    ) -> &mut Table {
+        let name = name.to_string();
+
+        match self.indexes.get_mut(&name) {
+            Some(index) if sequence > 1 => {
+                if primary && !self.primary_key.contains(&column.name()) {
+                    self.primary_key.push(column.name());
+                }
+
+                index.set_column_count(index.column_count().max(sequence));
+            }
+            _ => {
+                self.set_index(Index::new(name, column, primary, unique));
+            }
+        }
+
+        self
+    }
+
     /// Find an index by name
     pub fn index(&self, key: &str) -> Option<&Index> {
-        self.indexes.get(&key.to_string())
+        self.indexes.get(&Name(key))
     }
 
     /// Find an index by column name
@@ -118,6 +453,142 @@ impl Table {
         self.indexes.iter()
     }
 
+    /// Remove an index, dropping its column from the primary key if no other primary index covers it
+    pub fn remove_index(&mut self, key: &str) -> Option<Index> {
+        let index = self.indexes.shift_remove(&Name(key))?;
+
+        if index.primary()
+            && !self
+                .indexes
+                .values()
+                .any(|i| i.primary() && i.column().name() == index.column().name())
+        {
+            self.primary_key.retain(|k| *k != index.column().name());
+        }
+
+        Some(index)
+    }
+
+    /// Rename an index keeping its position, returns false if it is missing or the new name is taken
+    pub fn rename_index(&mut self, key: &str, name: impl ToString) -> bool {
+        rename_entry(&mut self.indexes, key, name, |index, name| {
+            index.set_name(name);
+        })
+    }
+
+    /// Add a check constraint
+    pub fn set_check_constraint(&mut self, check_constraint: CheckConstraint) -> &mut Table {
+        self.check_constraints
+            .insert(check_constraint.name(), check_constraint);
+
+        self
+    }
+
+    /// Find a check constraint by name
+    pub fn check_constraint(&self, key: &str) -> Option<&CheckConstraint> {
+        self.check_constraints.get(&Name(key))
+    }
+
+    /// Get check constraints iterator
+    pub fn check_constraints(&self) -> indexmap::map::Iter<'_, Arc<String>, CheckConstraint> {
+        self.check_constraints.iter()
+    }
+
+    /// Remove a check constraint
+    pub fn remove_check_constraint(&mut self, key: &str) -> Option<CheckConstraint> {
+        self.check_constraints.shift_remove(&Name(key))
+    }
+
+    /// Rename a check constraint keeping its position, returns false if it is missing or the new name is taken
+    pub fn rename_check_constraint(&mut self, key: &str, name: impl ToString) -> bool {
+        rename_entry(
+            &mut self.check_constraints,
+            key,
+            name,
+            |check_constraint, name| {
+                check_constraint.set_name(name);
+            },
+        )
+    }
+
+    /// Point indexes at the column instances held by the table, i.e. after deserialization
+    pub fn relink(&mut self) -> &mut Table {
+        for index in self.indexes.values_mut() {
+            if let Some(column) = self.columns.get(&index.column().name()) {
+                index.set_column(column.clone());
+            }
+        }
+
+        self
+    }
+
+    /// Check that columns, primary key, indexes and check constraints agree with each other
+    pub fn validate(&self) -> Result<(), Vec<IntegrityIssue>> {
+        let mut issues = Vec::new();
+
+        for (name, column) in self.columns.iter() {
+            if column.table() != self.name {
+                issues.push(IntegrityIssue::ColumnTableMismatch {
+                    table: self.name.clone(),
+                    column: name.clone(),
+                    column_table: column.table(),
+                });
+            }
+        }
+
+        for (position, key) in self.primary_key.iter().enumerate() {
+            if !self.columns.contains_key(key) {
+                issues.push(IntegrityIssue::MissingPrimaryKeyColumn {
+                    table: self.name.clone(),
+                    column: key.clone(),
+                });
+            }
+
+            if self.primary_key[..position].contains(key) {
+                issues.push(IntegrityIssue::DuplicateName {
+                    kind: "primary key column",
+                    name: key.clone(),
+                });
+            }
+        }
+
+        for (name, index) in self.indexes.iter() {
+            if index.column().table() != self.name
+                || !self.columns.contains_key(&index.column().name())
+            {
+                issues.push(IntegrityIssue::DanglingIndex {
+                    table: self.name.clone(),
+                    index: name.clone(),
+                    column: index.column().name(),
+                });
+            }
+        }
+
+        for (name, check_constraint) in self.check_constraints.iter() {
+            if check_constraint.table() != self.name {
+                issues.push(IntegrityIssue::DanglingCheckConstraint {
+                    table: check_constraint.table(),
+                    constraint: name.clone(),
+                    column: check_constraint.column().unwrap_or_default(),
+                });
+            } else if let Some(column) = check_constraint.column() {
+                if !self.columns.contains_key(&column) {
+                    issues.push(IntegrityIssue::DanglingCheckConstraint {
+                        table: self.name.clone(),
+                        constraint: name.clone(),
+                        column,
+                    });
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
     /// Get number of primary keys
     pub fn primary_key_count(&self) -> usize {
         self.primary_key.len()
@@ -150,3 +621,26 @@ impl Table {
             .collect::<Vec<Arc<Column>>>()
     }
 }
+
+/// Re-key a named map entry in place, applying the new name to the value as well
+fn rename_entry<T>(
+    map: &mut IndexMap<Arc<String>, T>,
+    key: &str,
+    name: impl ToString,
+    set_name: impl FnOnce(&mut T, &str),
+) -> bool {
+    let name = Arc::new(name.to_string());
+    let Some(position) = map.get_index_of(&Name(key)) else {
+        return false;
+    };
+    if map.contains_key(&name) {
+        return false;
+    }
+
+    if let Some((_, mut value)) = map.shift_remove_index(position) {
+        set_name(&mut value, name.as_str());
+        map.shift_insert(position, name, value);
+    }
+
+    true
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trigger {
+    name: Arc<String>,
+    table: Arc<String>,
+    timing: String,
+    event: String,
+    statement: String,
+    action_order: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definer: Option<String>,
+}
+
+impl Trigger {
+    /// Create a trigger on a table, firing at timing (BEFORE, AFTER) on event (INSERT, UPDATE, DELETE)
+    pub fn new(
+        name: impl ToString,
+        table: impl ToString,
+        timing: impl ToString,
+        event: impl ToString,
+        statement: impl ToString,
+    ) -> Trigger {
+        Trigger {
+            name: Arc::new(name.to_string()),
+            table: Arc::new(table.to_string()),
+            timing: timing.to_string(),
+            event: event.to_string(),
+            statement: statement.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Get trigger name
+    pub fn name(&self) -> Arc<String> {
+        self.name.clone()
+    }
+
+    /// Set table name, kept in sync through `Database::rename_table`
+    pub(crate) fn set_table(&mut self, table: impl ToString) -> &mut Trigger {
+        self.table = Arc::new(table.to_string());
+        self
+    }
+
+    /// Get table name
+    pub fn table(&self) -> Arc<String> {
+        self.table.clone()
+    }
+
+    /// Get trigger timing, i.e. BEFORE or AFTER
+    pub fn timing(&self) -> &str {
+        &self.timing
+    }
+
+    /// Get trigger event, i.e. INSERT, UPDATE or DELETE
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// Get trigger body
+    pub fn statement(&self) -> &str {
+        &self.statement
+    }
+
+    /// Set order among triggers with the same timing and event
+    pub fn set_action_order(&mut self, action_order: i64) -> &mut Trigger {
+        self.action_order = action_order;
+        self
+    }
+
+    /// Get order among triggers with the same timing and event
+    pub fn action_order(&self) -> i64 {
+        self.action_order
+    }
+
+    /// Set trigger definer, i.e. `user`@`host`
+    pub fn set_definer(&mut self, definer: Option<impl ToString>) -> &mut Trigger {
+        self.definer = definer.map(|d| d.to_string());
+        self
+    }
+
+    /// Get trigger definer if available
+    pub fn definer(&self) -> Option<&str> {
+        self.definer.as_deref()
+    }
+}
@@ -0,0 +1,135 @@
+use crate::metadata::serialize_sorted;
+use crate::metadata::WithMetadata;
+use crate::reflection::column::Column;
+use crate::reflection::name::Name;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct View {
+    name: Arc<String>,
+    definition: String,
+    columns: IndexMap<Arc<String>, Arc<Column>>,
+    updatable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check_option: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    security_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definer: Option<String>,
+    #[serde(serialize_with = "serialize_sorted")]
+    metadata: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    metadata_values: HashMap<String, Value>,
+}
+
+impl WithMetadata for View {
+    /// Borrow metadata container for reading
+    fn get_metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Borrow metadata container for writing
+    fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.metadata
+    }
+
+    /// Borrow structured metadata container for reading
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    /// Borrow structured metadata container for writing
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
+}
+
+impl View {
+    /// Create a new view with a name and its SELECT definition
+    pub fn new(name: impl ToString, definition: impl ToString) -> View {
+        View {
+            name: Arc::new(name.to_string()),
+            definition: definition.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Get view name
+    pub fn name(&self) -> Arc<String> {
+        self.name.clone()
+    }
+
+    /// Get view SELECT definition
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+
+    /// Add a column to the view
+    pub fn set_column(&mut self, column: Column) -> &mut View {
+        self.columns.insert(column.name(), Arc::new(column));
+
+        self
+    }
+
+    /// Find a column by name
+    pub fn column(&self, key: &str) -> Option<Arc<Column>> {
+        self.columns.get(&Name(key)).cloned()
+    }
+
+    /// Get columns iterator
+    pub fn columns(&self) -> indexmap::map::Iter<'_, Arc<String>, Arc<Column>> {
+        self.columns.iter()
+    }
+
+    /// Set flag indicating whether the view is updatable
+    pub fn set_updatable(&mut self, updatable: bool) -> &mut View {
+        self.updatable = updatable;
+        self
+    }
+
+    /// Get flag indicating whether the view is updatable
+    pub fn updatable(&self) -> bool {
+        self.updatable
+    }
+
+    /// Set WITH CHECK OPTION mode (CASCADED or LOCAL)
+    pub fn set_check_option(&mut self, check_option: Option<impl ToString>) -> &mut View {
+        self.check_option = check_option.map(|c| c.to_string());
+        self
+    }
+
+    /// Get WITH CHECK OPTION mode if available
+    pub fn check_option(&self) -> Option<&str> {
+        self.check_option.as_deref()
+    }
+
+    /// Set SQL SECURITY type (DEFINER or INVOKER)
+    pub fn set_security_type(&mut self, security_type: Option<impl ToString>) -> &mut View {
+        self.security_type = security_type.map(|s| s.to_string());
+        self
+    }
+
+    /// Get SQL SECURITY type if available
+    pub fn security_type(&self) -> Option<&str> {
+        self.security_type.as_deref()
+    }
+
+    /// Set view definer, i.e. `user`@`host`
+    pub fn set_definer(&mut self, definer: Option<impl ToString>) -> &mut View {
+        self.definer = definer.map(|d| d.to_string());
+        self
+    }
+
+    /// Get view definer if available
+    pub fn definer(&self) -> Option<&str> {
+        self.definer.as_deref()
+    }
+}
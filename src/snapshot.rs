@@ -0,0 +1,505 @@
+use crate::metadata::{metadata_entries, WithMetadata};
+use crate::reflection::{
+    CheckConstraint, Column, ColumnGeneration, Constraint, Database, DefaultValue, Event, Index,
+    IntegrityIssue, Partition, Routine, SqlDatatype, Table, Trigger, View,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Version written to new snapshots, other versions are refused.
+/// Nullability, keys, charset, collation and on update found in metadata are moved
+/// into their fields on load.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Failure to turn a snapshot back into a database
+#[derive(Clone, Debug, PartialEq)]
+pub enum SnapshotError {
+    /// Snapshot written by an incompatible format version
+    UnsupportedVersion(u32),
+    /// Indexes or constraints referencing columns missing from the snapshot
+    Integrity(Vec<IntegrityIssue>),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported snapshot version {}, expected {}",
+                version, SNAPSHOT_VERSION
+            ),
+            SnapshotError::Integrity(issues) => write!(
+                f,
+                "inconsistent snapshot: {}",
+                issues
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Canonical, versioned form of a database meant to be stored and diffed,
+/// objects are sorted by name and indexes and constraints reference columns as `table.column`.
+/// Values that change without a schema change (AUTO_INCREMENT, create and update times) are left out.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Snapshot {
+    version: u32,
+    name: String,
+    tables: Vec<TableSnapshot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    constraints: Vec<ConstraintSnapshot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    views: Vec<View>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    triggers: Vec<Trigger>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    routines: Vec<Routine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    events: Vec<Event>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct TableSnapshot {
+    name: String,
+    columns: Vec<ColumnSnapshot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    indexes: Vec<IndexSnapshot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    check_constraints: Vec<CheckConstraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    partitions: Vec<Partition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    engine: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    row_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    create_options: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checksum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ColumnSnapshot {
+    name: String,
+    datatype: SqlDatatype,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<DefaultValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation: Option<ColumnGeneration>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    invisible: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    nullable: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    primary: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    unique: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    auto_increment: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_update: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct IndexSnapshot {
+    name: String,
+    column: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    primary: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    unique: bool,
+    #[serde(default = "single_column", skip_serializing_if = "is_single_column")]
+    column_count: usize,
+}
+
+fn single_column() -> usize {
+    1
+}
+
+fn is_single_column(column_count: &usize) -> bool {
+    *column_count == 1
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ConstraintSnapshot {
+    name: String,
+    key_pairs: Vec<KeyPairSnapshot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreign_database: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, Value>,
+}
+
+/// Column pair referenced as `table.column`, a foreign column outside the database is embedded
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct KeyPairSnapshot {
+    local: String,
+    foreign: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreign_column: Option<ColumnSnapshot>,
+}
+
+impl Snapshot {
+    /// Capture the canonical form of a database
+    pub fn new(db: &Database) -> Snapshot {
+        let mut tables = db
+            .tables()
+            .map(|(_, t)| TableSnapshot::new(t))
+            .collect::<Vec<TableSnapshot>>();
+        tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut constraints = db
+            .constraints()
+            .map(|(_, c)| ConstraintSnapshot::new(c))
+            .collect::<Vec<ConstraintSnapshot>>();
+        constraints.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            name: db.name().to_string(),
+            tables,
+            constraints,
+            views: sorted(db.views().map(|(_, v)| (**v).clone()), |v| v.name()),
+            triggers: sorted(db.triggers().map(|(_, t)| (**t).clone()), |t| t.name()),
+            routines: sorted_routines(db),
+            events: sorted(db.events().map(|(_, e)| (**e).clone()), |e| e.name()),
+            charset: db.charset().map(|c| c.to_string()),
+            collation: db.collation().map(|c| c.to_string()),
+            metadata: metadata_entries(db),
+        }
+    }
+
+    /// Get the format version the snapshot was written with
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Get database name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Rebuild the database, indexes and constraints share the columns held by their tables
+    pub fn into_database(self) -> Result<Database, SnapshotError> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(self.version));
+        }
+
+        let mut db = Database::new(&self.name);
+        let mut issues = Vec::new();
+        for table in self.tables {
+            db.set_table(table.into_table(&mut issues));
+        }
+
+        // charset goes last so tables don't inherit one they were stored without
+        db.set_charset(self.charset).set_collation(self.collation);
+        for (key, value) in self.metadata {
+            db.set_meta_value(key, value);
+        }
+
+        for constraint in self.constraints {
+            if let Some(constraint) = constraint.into_constraint(&db, &mut issues) {
+                db.set_constraint(constraint);
+            }
+        }
+
+        for view in self.views {
+            db.set_view(view);
+        }
+        for trigger in self.triggers {
+            db.set_trigger(trigger);
+        }
+        for routine in self.routines {
+            db.set_routine(routine);
+        }
+        for event in self.events {
+            db.set_event(event);
+        }
+
+        if issues.is_empty() {
+            Ok(db)
+        } else {
+            Err(SnapshotError::Integrity(issues))
+        }
+    }
+}
+
+impl From<&Database> for Snapshot {
+    fn from(db: &Database) -> Self {
+        Snapshot::new(db)
+    }
+}
+
+impl TryFrom<Snapshot> for Database {
+    type Error = SnapshotError;
+
+    fn try_from(snapshot: Snapshot) -> Result<Self, Self::Error> {
+        snapshot.into_database()
+    }
+}
+
+impl TableSnapshot {
+    fn new(table: &Table) -> TableSnapshot {
+        let mut indexes = table
+            .indexes()
+            .map(|(_, i)| IndexSnapshot {
+                name: i.name().to_string(),
+                column: i.column().name().to_string(),
+                primary: i.primary(),
+                unique: i.unique(),
+                column_count: i.column_count(),
+            })
+            .collect::<Vec<IndexSnapshot>>();
+        indexes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        TableSnapshot {
+            name: table.name().to_string(),
+            columns: table
+                .columns()
+                .map(|(_, c)| ColumnSnapshot::new(c))
+                .collect(),
+            indexes,
+            check_constraints: sorted(table.check_constraints().map(|(_, c)| c.clone()), |c| {
+                c.name()
+            }),
+            partitions: table.partitions().map(|(_, p)| p.clone()).collect(),
+            comment: table.comment().map(|c| c.to_string()),
+            engine: table.engine().map(|e| e.to_string()),
+            row_format: table.row_format().map(|r| r.to_string()),
+            create_options: table.create_options().map(|c| c.to_string()),
+            checksum: table.checksum().map(|c| c.to_string()),
+            charset: table.charset().map(|c| c.to_string()),
+            collation: table.collation().map(|c| c.to_string()),
+            metadata: metadata_entries(table),
+        }
+    }
+
+    fn into_table(self, issues: &mut Vec<IntegrityIssue>) -> Table {
+        let mut table = Table::new(&self.name);
+        table
+            .set_comment(self.comment)
+            .set_engine(self.engine)
+            .set_row_format(self.row_format)
+            .set_create_options(self.create_options)
+            .set_checksum(self.checksum);
+
+        for column in self.columns {
+            table.set_column(column.into_column(&self.name));
+        }
+
+        table
+            .set_charset(self.charset)
+            .set_collation(self.collation);
+        for (key, value) in self.metadata {
+            table.set_meta_value(key, value);
+        }
+
+        for index in self.indexes {
+            match table.column(&index.column) {
+                Some(column) => {
+                    let mut restored = Index::new(index.name, column, index.primary, index.unique);
+                    restored.set_column_count(index.column_count);
+                    table.set_index(restored);
+                }
+                None => issues.push(IntegrityIssue::DanglingIndex {
+                    table: table.name(),
+                    index: Arc::new(index.name),
+                    column: Arc::new(index.column),
+                }),
+            }
+        }
+
+        for check_constraint in self.check_constraints {
+            table.set_check_constraint(check_constraint);
+        }
+
+        for partition in self.partitions {
+            table.set_partition(partition);
+        }
+
+        table
+    }
+}
+
+impl ColumnSnapshot {
+    fn new(column: &Column) -> ColumnSnapshot {
+        ColumnSnapshot {
+            name: column.name().to_string(),
+            datatype: column.datatype().clone(),
+            default: column.default(),
+            comment: column.comment().map(|c| c.to_string()),
+            generation: column.generation().cloned(),
+            invisible: column.invisible(),
+            nullable: column.nullable(),
+            primary: column.primary(),
+            unique: column.unique(),
+            auto_increment: column.auto_increment(),
+            charset: column.charset().map(|c| c.to_string()),
+            collation: column.collation().map(|c| c.to_string()),
+            on_update: column.on_update().map(|o| o.to_string()),
+            metadata: metadata_entries(column),
+        }
+    }
+
+    fn into_column(self, table: &str) -> Column {
+        let mut column = Column::new(table, self.name, self.datatype);
+        column
+            .set_default(self.default)
+            .set_comment(self.comment)
+            .set_invisible(self.invisible)
+            .set_nullable(self.nullable)
+            .set_primary(self.primary)
+            .set_unique(self.unique)
+            .set_auto_increment(self.auto_increment)
+            .set_charset(self.charset)
+            .set_collation(self.collation)
+            .set_on_update(self.on_update);
+
+        if let Some(generation) = self.generation {
+            column.set_generated(generation.kind, generation.expression);
+        }
+
+        for (key, value) in self.metadata {
+            column.set_meta_value(key, value);
+        }
+
+        column
+    }
+}
+
+impl ConstraintSnapshot {
+    fn new(constraint: &Constraint) -> ConstraintSnapshot {
+        ConstraintSnapshot {
+            name: constraint.name().to_string(),
+            key_pairs: constraint
+                .key_pairs()
+                .map(|p| KeyPairSnapshot {
+                    local: qualified_name(&p.local),
+                    foreign: qualified_name(&p.foreign),
+                    foreign_column: constraint
+                        .is_cross_database()
+                        .then(|| ColumnSnapshot::new(&p.foreign)),
+                })
+                .collect(),
+            foreign_database: constraint.foreign_database().map(|d| d.to_string()),
+            metadata: metadata_entries(constraint),
+        }
+    }
+
+    fn into_constraint(
+        self,
+        db: &Database,
+        issues: &mut Vec<IntegrityIssue>,
+    ) -> Option<Constraint> {
+        let mut constraint: Option<Constraint> = None;
+
+        for key_pair in self.key_pairs {
+            let local = resolve(db, &key_pair.local);
+            let foreign = match key_pair.foreign_column {
+                Some(column) => {
+                    let (table, _) = split_qualified_name(&key_pair.foreign);
+                    Some(Arc::new(column.into_column(table)))
+                }
+                None => resolve(db, &key_pair.foreign),
+            };
+
+            let (local, foreign) = match (local, foreign) {
+                (Some(local), Some(foreign)) => (local, foreign),
+                (local, _) => {
+                    let missing = if local.is_none() {
+                        &key_pair.local
+                    } else {
+                        &key_pair.foreign
+                    };
+                    let (table, column) = split_qualified_name(missing);
+                    issues.push(IntegrityIssue::DanglingConstraint {
+                        constraint: Arc::new(self.name.clone()),
+                        table: Arc::new(table.to_string()),
+                        column: Arc::new(column.to_string()),
+                    });
+
+                    return None;
+                }
+            };
+
+            match constraint.as_mut() {
+                Some(c) => {
+                    c.add_key_pair(local, foreign);
+                }
+                None => constraint = Some(Constraint::new(&self.name, local, foreign)),
+            }
+        }
+
+        let mut constraint = constraint?;
+        constraint.set_foreign_database(self.foreign_database);
+        for (key, value) in self.metadata {
+            constraint.set_meta_value(key, value);
+        }
+
+        Some(constraint)
+    }
+}
+
+/// Collect items sorted by name
+fn sorted<T>(items: impl Iterator<Item = T>, name: impl Fn(&T) -> Arc<String>) -> Vec<T> {
+    let mut items = items.collect::<Vec<T>>();
+    items.sort_by_key(|item| name(item));
+
+    items
+}
+
+/// Sort routines by name, a procedure before a function of the same name
+fn sorted_routines(db: &Database) -> Vec<Routine> {
+    let mut routines = db
+        .routines()
+        .map(|(_, r)| (**r).clone())
+        .collect::<Vec<Routine>>();
+    routines.sort_by_key(|r| (r.name(), *r.kind()));
+
+    routines
+}
+
+fn qualified_name(column: &Column) -> String {
+    format!("{}.{}", column.table(), column.name())
+}
+
+fn split_qualified_name(name: &str) -> (&str, &str) {
+    name.split_once('.').unwrap_or((name, ""))
+}
+
+/// Find the column shared by its table from a `table.column` reference
+fn resolve(db: &Database, name: &str) -> Option<Arc<Column>> {
+    let (table, column) = split_qualified_name(name);
+
+    db.table(table).and_then(|t| t.column(column))
+}
@@ -0,0 +1,136 @@
+use database_reflection::builder::types::{int, timestamp, tinyint, varchar};
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::metadata::consts::*;
+use database_reflection::metadata::WithMetadata;
+use database_reflection::reflection::{ConstraintSide, DefaultValue, IntegrityIssue};
+use std::sync::Arc;
+
+#[test]
+fn test_builder_integrity() {
+    let db = DatabaseBuilder::new("test")
+        .charset("utf8mb4", "utf8mb4_unicode_ci")
+        .table("clients", |t| {
+            t.charset("utf8mb4", "utf8mb4_unicode_ci")
+                .column("client_id", int(10).unsigned())
+                .primary()
+                .auto_increment()
+                .column("email", varchar(255))
+                .unique()
+                .column("phone", varchar(45))
+                .nullable()
+                .column("is_email_verified", tinyint(1).unsigned())
+                .default(DefaultValue::Value(serde_json::Value::from(0)))
+                .column("created", timestamp())
+                .default(DefaultValue::Expression("current_timestamp()".to_string()))
+        })
+        .table("products", |t| {
+            t.table_comment("Product catalogue")
+                .column("product_id", int(10).unsigned())
+                .primary()
+                .column("name", varchar(255))
+                .nullable()
+                .comment("Display name")
+        })
+        .table("client_products", |t| {
+            t.column("client_product_id", int(10).unsigned())
+                .primary()
+                .column("client_id", int(10).unsigned())
+                .index("fk_client_products_1_idx")
+                .references("clients.client_id")
+                .on_delete(METADATA_CASCADE)
+                .on_update(METADATA_CASCADE)
+                .column("product_id", int(10).unsigned())
+                .index("fk_client_products_2_idx")
+                .references("products.product_id")
+        })
+        .build()
+        .unwrap();
+
+    let clients = db.table("clients").unwrap();
+    assert_eq!(clients.columns().len(), 5);
+    assert_eq!(
+        clients.primary_key(),
+        Some(Arc::new("client_id".to_string()))
+    );
+    assert!(clients.index("PRIMARY").unwrap().primary());
+    assert!(clients.index("email").unwrap().unique());
+    assert!(clients
+        .column("client_id")
+        .unwrap()
+        .meta_flag(METADATA_FLAG_AUTO_INCREMENT));
+    assert!(clients
+        .column("client_id")
+        .unwrap()
+        .meta_flag(METADATA_FLAG_UNSIGNED));
+    assert!(clients
+        .column("phone")
+        .unwrap()
+        .meta_flag(METADATA_FLAG_NULLABLE));
+    assert_eq!(
+        clients.column("email").unwrap().meta(METADATA_CHARSET),
+        Some("utf8mb4".to_string())
+    );
+
+    let products = db.table("products").unwrap();
+    assert_eq!(products.comment(), Some("Product catalogue"));
+    assert_eq!(
+        products.column("name").unwrap().comment(),
+        Some("Display name")
+    );
+    assert_eq!(
+        products.meta(METADATA_COLLATION),
+        Some("utf8mb4_unicode_ci".to_string())
+    );
+
+    let constraint = db.constraint("fk_client_products_1").unwrap();
+    assert_eq!(constraint.foreign().table().as_str(), "clients");
+    assert_eq!(
+        constraint.meta(METADATA_ON_DELETE),
+        Some(METADATA_CASCADE.to_string())
+    );
+    assert!(Arc::ptr_eq(
+        &constraint.key_pairs().next().unwrap().foreign,
+        &clients.column("client_id").unwrap()
+    ));
+    assert!(db.constraint("fk_client_products_2").is_some());
+    assert_eq!(
+        db.constraints_by_table(products.clone(), Some(ConstraintSide::Foreign))
+            .len(),
+        1
+    );
+    assert_eq!(
+        db.table("client_products")
+            .unwrap()
+            .index("fk_client_products_2_idx")
+            .unwrap()
+            .column()
+            .name()
+            .as_str(),
+        "product_id"
+    );
+
+    // unresolved references and type mismatches are reported
+    let issues = DatabaseBuilder::new("broken")
+        .table("clients", |t| {
+            t.column("client_id", int(10).unsigned()).primary()
+        })
+        .table("orders", |t| {
+            t.column("client_id", int(10))
+                .references("clients.client_id")
+                .column("vendor_id", int(10))
+                .references_as("fk_vendor", "vendors.vendor_id")
+        })
+        .build()
+        .unwrap_err();
+
+    assert_eq!(issues.len(), 2);
+    assert!(issues.contains(&IntegrityIssue::DanglingConstraint {
+        constraint: Arc::new("fk_vendor".to_string()),
+        table: Arc::new("vendors".to_string()),
+        column: Arc::new("vendor_id".to_string()),
+    }));
+    assert!(issues.iter().any(|i| matches!(
+        i,
+        IntegrityIssue::ConstraintTypeMismatch { constraint, .. } if constraint.as_str() == "fk_orders_1"
+    )));
+}
@@ -0,0 +1,71 @@
+use database_reflection::reflection::{
+    Catalog, Column, Constraint, ConstraintSide, Database, SqlDatatype, SqlSigned, Table,
+};
+use std::sync::Arc;
+
+#[test]
+fn test_catalog_integrity() {
+    let mut accounts = Database::new("accounts");
+    accounts.set_table(
+        Table::new("users")
+            .set_column(Column::new(
+                "users",
+                "user_id",
+                SqlDatatype::Int(10, SqlSigned::Unsigned),
+            ))
+            .to_owned(),
+    );
+
+    let mut shop = Database::new("shop");
+    shop.set_table(
+        Table::new("users")
+            .set_column(Column::new(
+                "users",
+                "user_id",
+                SqlDatatype::Int(10, SqlSigned::Unsigned),
+            ))
+            .to_owned(),
+    );
+    shop.set_table(
+        Table::new("orders")
+            .set_column(Column::new(
+                "orders",
+                "user_id",
+                SqlDatatype::Int(10, SqlSigned::Unsigned),
+            ))
+            .to_owned(),
+    );
+
+    let local_column = shop.table("orders").unwrap().column("user_id").unwrap();
+    let foreign_column = accounts.table("users").unwrap().column("user_id").unwrap();
+    shop.set_constraint(
+        Constraint::new("fk_orders_1", local_column, foreign_column)
+            .set_foreign_database(Some("accounts"))
+            .to_owned(),
+    );
+
+    assert!(shop.constraint("fk_orders_1").unwrap().is_cross_database());
+    // a same named table in the local database is not the foreign side
+    assert!(shop
+        .constraints_by_table(shop.table("users").unwrap(), Some(ConstraintSide::Foreign))
+        .is_empty());
+
+    let mut catalog = Catalog::new();
+    catalog.set_database(accounts).set_database(shop);
+
+    assert_eq!(catalog.databases().len(), 2);
+    assert!(catalog.database("shop").is_some());
+    assert!(catalog.table("accounts", "users").is_some());
+    assert!(catalog.table("accounts", "orders").is_none());
+
+    let cross = catalog.cross_database_constraints();
+    assert_eq!(cross.len(), 1);
+    assert_eq!(cross[0].0.as_str(), "shop");
+
+    let foreign_table = catalog.foreign_table("shop", &cross[0].1).unwrap();
+    assert_eq!(foreign_table.name().as_str(), "users");
+    assert!(Arc::ptr_eq(
+        &foreign_table,
+        &catalog.table("accounts", "users").unwrap()
+    ));
+}
@@ -0,0 +1,38 @@
+use database_reflection::reflection::{CheckConstraint, Table};
+
+#[test]
+fn test_check_constraint_integrity() {
+    let column_check = CheckConstraint::new("price", "products", "`price` >= 0")
+        .set_column(Some("price"))
+        .to_owned();
+
+    assert_eq!(column_check.name(), String::from("price").into());
+    assert_eq!(column_check.table(), String::from("products").into());
+    assert_eq!(column_check.column(), Some(String::from("price").into()));
+    assert_eq!(column_check.expression(), "`price` >= 0");
+
+    let table_check = CheckConstraint::new(
+        "chk_products_dates",
+        "products",
+        "`available_from` < `available_to`",
+    );
+
+    assert_eq!(table_check.column(), None);
+
+    let mut table = Table::new("products");
+    table
+        .set_check_constraint(column_check)
+        .set_check_constraint(table_check);
+
+    assert_eq!(table.check_constraints().len(), 2);
+    assert!(table.check_constraint("chk_products_dates").is_some());
+
+    let json = serde_json::to_string(&table).unwrap();
+    assert!(json.contains("chk_products_dates"));
+
+    let restored: Table = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.check_constraint("price").unwrap().expression(),
+        "`price` >= 0"
+    );
+}
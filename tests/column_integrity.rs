@@ -4,8 +4,8 @@ use database_reflection::metadata::consts::{
 };
 use database_reflection::metadata::WithMetadata;
 use database_reflection::reflection::{
-    Column, DefaultValue, JsonDatatype, JsonNumber, JsonString, RustDatatype, SqlDatatype,
-    SqlSigned,
+    Column, ColumnGeneration, DefaultValue, GeneratedKind, JsonDatatype, JsonNumber, JsonString,
+    RustDatatype, SqlDatatype, SqlSigned,
 };
 use serde_json::Value;
 
@@ -41,6 +41,7 @@ fn test_column_integrity() {
     ))));
     column_vc.set_meta(METADATA_COLLATION, "utf8mb4");
     column_vc.set_meta(METADATA_CHARSET, "utf8mb4_unicode_ci");
+    column_vc.set_comment(Some("Arbitrary value"));
 
     assert_eq!(column_vc.name(), String::from("value").into());
     assert_eq!(column_vc.table(), String::from("test").into());
@@ -66,4 +67,28 @@ fn test_column_integrity() {
         Some(String::from("utf8mb4_unicode_ci"))
     );
     assert!(!column_vc.meta_flag(METADATA_FLAG_NULLABLE));
+    assert_eq!(column_vc.comment(), Some("Arbitrary value"));
+    assert_eq!(column_pk.comment(), None);
+
+    assert!(!column_vc.is_generated());
+    assert!(!column_vc.invisible());
+
+    let mut column_gen = Column::new(
+        "test",
+        "value_length",
+        SqlDatatype::Int(10, SqlSigned::Unsigned),
+    );
+    column_gen
+        .set_generated(GeneratedKind::Stored, "octet_length(`value`)")
+        .set_invisible(true);
+
+    assert!(column_gen.is_generated());
+    assert_eq!(
+        column_gen.generation(),
+        Some(&ColumnGeneration {
+            kind: GeneratedKind::Stored,
+            expression: "octet_length(`value`)".to_string()
+        })
+    );
+    assert!(column_gen.invisible());
 }
@@ -1,3 +1,5 @@
+use database_reflection::builder::types::int;
+use database_reflection::builder::DatabaseBuilder;
 use database_reflection::metadata::consts::{METADATA_CASCADE, METADATA_ON_DELETE};
 use database_reflection::metadata::WithMetadata;
 use database_reflection::reflection::{Column, Constraint, SqlDatatype, SqlSigned};
@@ -48,3 +50,44 @@ fn test_constraint_integrity() {
     assert_eq!(constraint.key_pairs_count(), 2);
     assert_eq!(constraint.key_pairs().len(), 2);
 }
+
+#[test]
+fn test_constraint_key_pairs_by_name() {
+    let mut db = DatabaseBuilder::new("shop")
+        .table("warehouses", |t| {
+            t.column("warehouse_id", int(10).unsigned())
+                .primary()
+                .column("region_id", int(10).unsigned())
+                .primary()
+        })
+        .table("stock", |t| {
+            t.column("warehouse_id", int(10).unsigned())
+                .column("region_id", int(10).unsigned())
+        })
+        .build()
+        .unwrap();
+
+    assert!(db.add_constraint_key_pair(
+        "fk_stock_1",
+        ("stock", "warehouse_id"),
+        ("warehouses", "warehouse_id")
+    ));
+    assert!(db.add_constraint_key_pair(
+        "fk_stock_1",
+        ("stock", "region_id"),
+        ("warehouses", "region_id")
+    ));
+    assert!(!db.add_constraint_key_pair(
+        "fk_stock_2",
+        ("stock", "region_id"),
+        ("regions", "region_id")
+    ));
+
+    let constraint = db.constraint("fk_stock_1").unwrap();
+    assert_eq!(constraint.key_pairs_count(), 2);
+    assert!(Arc::ptr_eq(
+        &constraint.key_pairs().nth(1).unwrap().foreign,
+        &db.table("warehouses").unwrap().column("region_id").unwrap()
+    ));
+    assert!(db.constraint("fk_stock_2").is_none());
+}
@@ -1,6 +1,7 @@
 use database_reflection::metadata::consts::*;
 
 #[test]
+#[allow(clippy::len_zero)]
 fn test_consts_are_public() {
     assert!(METADATA_CHARSET.len() > 0);
     assert!(METADATA_COLLATION.len() > 0);
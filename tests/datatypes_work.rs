@@ -6,6 +6,10 @@ use serde_json::Value;
 use std::str::FromStr;
 
 #[test]
+#[allow(
+    clippy::unnecessary_fallible_conversions,
+    clippy::assertions_on_constants
+)]
 fn test_datatypes() {
     assert_eq!(
         SqlDatatype::try_from("tinyint(1) unsigned"),
@@ -458,4 +462,60 @@ fn test_datatypes() {
     } else {
         assert!(false);
     }
+
+    assert_eq!(
+        DefaultValue::from_sql("0", &SqlDatatype::Int(10, SqlSigned::Unsigned)),
+        DefaultValue::Value(Value::from(0u64))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("-5", &SqlDatatype::Int(10, SqlSigned::Signed)),
+        DefaultValue::Value(Value::from(-5i64))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("1", &SqlDatatype::Tinyint(1, SqlSigned::Unsigned)),
+        DefaultValue::Value(Value::Bool(true))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("1", &SqlDatatype::Tinyint(1, SqlSigned::Signed)),
+        DefaultValue::Value(Value::Bool(true))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("1.50", &SqlDatatype::Decimal(10, 2, SqlSigned::Signed)),
+        DefaultValue::Value(Value::from("1.50"))
+    );
+    assert_eq!(
+        DefaultValue::from_sql(
+            "12345678901234.123456",
+            &SqlDatatype::Decimal(20, 6, SqlSigned::Signed)
+        ),
+        DefaultValue::Value(Value::from("12345678901234.123456"))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("1.5", &SqlDatatype::Double(10, 2, SqlSigned::Signed)),
+        DefaultValue::Value(Value::from(1.5))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("'abc'", &SqlDatatype::Varchar(45)),
+        DefaultValue::Value(Value::from("abc"))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("'it''s'", &SqlDatatype::Varchar(45)),
+        DefaultValue::Value(Value::from("it's"))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("'7'", &SqlDatatype::Int(10, SqlSigned::Signed)),
+        DefaultValue::Value(Value::from(7i64))
+    );
+    assert_eq!(
+        DefaultValue::from_sql("NULL", &SqlDatatype::Varchar(45)),
+        DefaultValue::Null
+    );
+    assert_eq!(
+        DefaultValue::from_sql("current_timestamp()", &SqlDatatype::Timestamp),
+        DefaultValue::Expression("current_timestamp()".to_string())
+    );
+    assert_eq!(
+        DefaultValue::from_sql("(json_array())", &SqlDatatype::Text(65535)),
+        DefaultValue::Expression("(json_array())".to_string())
+    );
 }
@@ -0,0 +1,106 @@
+use database_reflection::builder::types::int;
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::graph::{DependencyCycle, DependencyGraph};
+use database_reflection::reflection::ConstraintSide;
+use std::sync::Arc;
+
+fn names(tables: &[Arc<String>]) -> Vec<&str> {
+    tables.iter().map(|t| t.as_str()).collect()
+}
+
+#[test]
+fn test_dependency_graph_integrity() {
+    let db = DatabaseBuilder::new("shop")
+        .table("client_products", |t| {
+            t.column("client_id", int(10))
+                .references("clients.client_id")
+                .column("product_id", int(10))
+                .references("products.product_id")
+        })
+        .table("products", |t| {
+            t.column("product_id", int(10))
+                .primary()
+                .column("parent_id", int(10))
+                .references("products.product_id")
+        })
+        .table("clients", |t| t.column("client_id", int(10)).primary())
+        .table("client_tokens", |t| {
+            t.column("client_id", int(10))
+                .references("clients.client_id")
+        })
+        .build()
+        .unwrap();
+
+    let graph = DependencyGraph::new(&db);
+    assert_eq!(graph.edges().len(), 4);
+    assert_eq!(
+        names(&graph.dependencies("client_products")),
+        vec!["clients", "products"]
+    );
+    assert_eq!(
+        names(&graph.dependents("clients")),
+        vec!["client_products", "client_tokens"]
+    );
+
+    let order = graph.topological_order().unwrap();
+    assert_eq!(
+        names(&order),
+        vec!["products", "clients", "client_products", "client_tokens"]
+    );
+    assert_eq!(
+        names(&graph.reverse_order().unwrap()),
+        vec!["client_tokens", "client_products", "clients", "products"]
+    );
+
+    // self references are cycles, but do not prevent ordering
+    assert_eq!(
+        graph.cycles(),
+        vec![DependencyCycle {
+            tables: vec![Arc::new("products".to_string())],
+            constraints: vec![Arc::new("fk_products_1".to_string())],
+        }]
+    );
+
+    assert_eq!(
+        names(&graph.reachable_from("client_tokens", Some(ConstraintSide::Local))),
+        vec!["clients"]
+    );
+    assert_eq!(
+        names(&graph.reachable_from("products", Some(ConstraintSide::Foreign))),
+        vec!["client_products"]
+    );
+    assert_eq!(
+        names(&graph.reachable_from("client_tokens", None)),
+        vec!["client_products", "products", "clients"]
+    );
+
+    let db = DatabaseBuilder::new("loop")
+        .table("a", |t| {
+            t.column("id", int(10))
+                .primary()
+                .column("b_id", int(10))
+                .references("b.id")
+        })
+        .table("b", |t| {
+            t.column("id", int(10))
+                .primary()
+                .column("a_id", int(10))
+                .references("a.id")
+        })
+        .table("c", |t| t.column("a_id", int(10)).references("a.id"))
+        .build()
+        .unwrap();
+
+    let graph = DependencyGraph::new(&db);
+    assert!(graph.has_cycles());
+    assert_eq!(
+        graph.topological_order().unwrap_err(),
+        vec![DependencyCycle {
+            tables: vec![Arc::new("a".to_string()), Arc::new("b".to_string())],
+            constraints: vec![
+                Arc::new("fk_a_1".to_string()),
+                Arc::new("fk_b_1".to_string())
+            ],
+        }]
+    );
+}
@@ -0,0 +1,103 @@
+use database_reflection::builder::types::{int, varchar};
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::reflection::{Catalog, Constraint, Database};
+use std::sync::Arc;
+
+#[test]
+fn test_deserialize_integrity() {
+    let db = DatabaseBuilder::new("shop")
+        .table("clients", |t| {
+            t.column("client_id", int(10).unsigned())
+                .primary()
+                .column("email", varchar(255))
+                .unique()
+        })
+        .table("orders", |t| {
+            t.column("order_id", int(10).unsigned())
+                .primary()
+                .column("client_id", int(10).unsigned())
+                .index("fk_orders_1_idx")
+                .references("clients.client_id")
+        })
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_string(&db).unwrap();
+    let restored: Database = serde_json::from_str(&json).unwrap();
+
+    // indexes share the columns held by their table
+    let clients = restored.table("clients").unwrap();
+    let email = clients.column("email").unwrap();
+    assert!(std::ptr::eq(
+        clients.index("email").unwrap().column(),
+        &*email
+    ));
+
+    // constraints share the columns held by both tables
+    let orders = restored.table("orders").unwrap();
+    let fk = restored.constraint("fk_orders_1").unwrap();
+    let pair = fk.key_pairs().next().unwrap();
+    assert!(Arc::ptr_eq(
+        &pair.local,
+        &orders.column("client_id").unwrap()
+    ));
+    assert!(Arc::ptr_eq(
+        &pair.foreign,
+        &clients.column("client_id").unwrap()
+    ));
+    assert!(restored.validate().is_ok());
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+    // cross database constraints are relinked through the catalog
+    let accounts = DatabaseBuilder::new("accounts")
+        .table("users", |t| {
+            t.column("user_id", int(10).unsigned()).primary()
+        })
+        .build()
+        .unwrap();
+    let mut billing = DatabaseBuilder::new("billing")
+        .table("invoices", |t| t.column("user_id", int(10).unsigned()))
+        .build()
+        .unwrap();
+    billing.set_constraint(
+        Constraint::new(
+            "fk_invoices_1",
+            billing
+                .table("invoices")
+                .unwrap()
+                .column("user_id")
+                .unwrap(),
+            accounts.table("users").unwrap().column("user_id").unwrap(),
+        )
+        .set_foreign_database(Some("accounts"))
+        .to_owned(),
+    );
+
+    let mut catalog = Catalog::new();
+    catalog.set_database(accounts).set_database(billing);
+
+    let restored: Catalog =
+        serde_json::from_str(&serde_json::to_string(&catalog).unwrap()).unwrap();
+    let fk = restored
+        .database("billing")
+        .unwrap()
+        .constraint("fk_invoices_1")
+        .unwrap();
+    let pair = fk.key_pairs().next().unwrap();
+    assert!(Arc::ptr_eq(
+        &pair.local,
+        &restored
+            .table("billing", "invoices")
+            .unwrap()
+            .column("user_id")
+            .unwrap()
+    ));
+    assert!(Arc::ptr_eq(
+        &pair.foreign,
+        &restored
+            .table("accounts", "users")
+            .unwrap()
+            .column("user_id")
+            .unwrap()
+    ));
+}
@@ -146,16 +146,16 @@ fn get_mock_db() -> Database {
         .set_column(
             Column::new(clients_table_name, "last_access", SqlDatatype::Timestamp)
                 .set_meta(METADATA_ON_UPDATE, "current_timestamp()")
-                .set_default(Some(DefaultValue::Value(serde_json::Value::from(
-                    "current_timestamp()",
-                ))))
+                .set_default(Some(DefaultValue::Expression(
+                    "current_timestamp()".to_string(),
+                )))
                 .to_owned(),
         )
         .set_column(
             Column::new(clients_table_name, "created", SqlDatatype::Timestamp)
-                .set_default(Some(DefaultValue::Value(serde_json::Value::from(
-                    "current_timestamp()",
-                ))))
+                .set_default(Some(DefaultValue::Expression(
+                    "current_timestamp()".to_string(),
+                )))
                 .to_owned(),
         );
 
@@ -233,16 +233,16 @@ fn get_mock_db() -> Database {
                 SqlDatatype::Timestamp,
             )
             .set_meta(METADATA_ON_UPDATE, "current_timestamp()")
-            .set_default(Some(DefaultValue::Value(serde_json::Value::from(
-                "current_timestamp()",
-            ))))
+            .set_default(Some(DefaultValue::Expression(
+                "current_timestamp()".to_string(),
+            )))
             .to_owned(),
         )
         .set_column(
             Column::new(client_tokens_table_name, "created", SqlDatatype::Timestamp)
-                .set_default(Some(DefaultValue::Value(serde_json::Value::from(
-                    "current_timestamp()",
-                ))))
+                .set_default(Some(DefaultValue::Expression(
+                    "current_timestamp()".to_string(),
+                )))
                 .to_owned(),
         );
 
@@ -279,6 +279,8 @@ fn get_mock_db() -> Database {
         .set_meta(METADATA_CHARSET, "utf8mb4")
         .set_meta(METADATA_COLLATION, "utf8mb4_unicode_ci");
 
+    products_table.set_comment(Some("Product catalogue"));
+
     products_table
         .set_column(
             Column::new(
@@ -294,6 +296,7 @@ fn get_mock_db() -> Database {
         .set_column(
             Column::new(products_table_name, "name", SqlDatatype::Varchar(255))
                 .set_meta_flag(METADATA_FLAG_NULLABLE)
+                .set_comment(Some("Display name"))
                 .to_owned(),
         )
         .set_column(
@@ -448,6 +451,7 @@ fn get_mock_db() -> Database {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn construction() {
     let db = get_mock_db();
 
@@ -465,6 +469,20 @@ fn construction() {
         Some("utf8mb4".to_string())
     );
 
+    assert_eq!(
+        db.table("products").unwrap().comment(),
+        Some("Product catalogue")
+    );
+    assert_eq!(
+        db.table("products")
+            .unwrap()
+            .column("name")
+            .unwrap()
+            .comment(),
+        Some("Display name")
+    );
+    assert_eq!(db.table("clients").unwrap().comment(), None);
+
     let col_list = vec!["client_product_id", "client_id", "product_id"];
     for (column_name, column) in db.table("client_products").unwrap().columns() {
         assert_eq!(column_name.as_str(), column.name().as_str());
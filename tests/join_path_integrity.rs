@@ -0,0 +1,60 @@
+use database_reflection::builder::types::int;
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::reflection::ConstraintSide;
+
+#[test]
+fn test_join_path_integrity() {
+    let db = DatabaseBuilder::new("shop")
+        .table("clients", |t| t.column("client_id", int(10)).primary())
+        .table("products", |t| t.column("product_id", int(10)).primary())
+        .table("client_products", |t| {
+            t.column("client_product_id", int(10))
+                .primary()
+                .column("client_id", int(10))
+                .references("clients.client_id")
+                .column("product_id", int(10))
+                .references("products.product_id")
+        })
+        .table("favourites", |t| {
+            t.column("client_id", int(10))
+                .references("clients.client_id")
+                .column("product_id", int(10))
+                .references("products.product_id")
+        })
+        .table("warehouses", |t| {
+            t.column("warehouse_id", int(10)).primary()
+        })
+        .build()
+        .unwrap();
+
+    let paths = db.join_paths("client_products", "clients");
+    assert_eq!(paths.len(), 1);
+    let step = paths[0].steps().next().unwrap();
+    assert_eq!(step.direction, ConstraintSide::Local);
+    assert_eq!(step.constraint.as_str(), "fk_client_products_1");
+    assert_eq!(
+        paths[0].to_sql(),
+        "JOIN `clients` ON `clients`.`client_id` = `client_products`.`client_id`"
+    );
+
+    let paths = db.join_paths("clients", "products");
+    assert_eq!(paths.len(), 2);
+    assert!(paths.iter().all(|p| p.len() == 2));
+    assert_eq!(
+        paths[0].steps().next().unwrap().direction,
+        ConstraintSide::Foreign
+    );
+    assert_eq!(
+        paths[0].to_sql(),
+        "JOIN `client_products` ON `client_products`.`client_id` = `clients`.`client_id`\n\
+         JOIN `products` ON `products`.`product_id` = `client_products`.`product_id`"
+    );
+    assert_eq!(
+        paths[1].steps().next().unwrap().to_table.as_str(),
+        "favourites"
+    );
+
+    assert!(db.join_paths("clients", "clients")[0].is_empty());
+    assert!(db.join_paths("clients", "warehouses").is_empty());
+    assert!(db.join_paths("clients", "missing").is_empty());
+}
@@ -0,0 +1,170 @@
+use database_reflection::builder::types::{double, int, varchar};
+use database_reflection::builder::TableBuilder;
+use database_reflection::lint::{
+    LintLocation, LintRule, Linter, MissingPrimaryKey, NullableUniqueColumn, Severity,
+    UnindexedForeignKey,
+};
+use database_reflection::metadata::consts::{METADATA_CHARSET, METADATA_COLLATION};
+use database_reflection::reflection::{Constraint, Database};
+use std::sync::Arc;
+
+struct NoLegacyTables;
+
+impl LintRule for NoLegacyTables {
+    fn name(&self) -> &str {
+        "no_legacy_tables"
+    }
+
+    fn check(&self, db: &Database) -> Vec<(LintLocation, String)> {
+        db.tables()
+            .filter(|(name, _)| name.starts_with("legacy_"))
+            .map(|(name, _)| {
+                (
+                    LintLocation::Table(name.clone()),
+                    String::from("legacy table"),
+                )
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_lint_integrity() {
+    let mut db = Database::new("shop");
+    db.set_table(
+        TableBuilder::new("clients")
+            .charset("utf8mb4", "utf8mb4_unicode_ci")
+            .column("client_id", int(10).unsigned())
+            .primary()
+            .column("email", varchar(255))
+            .nullable()
+            .unique()
+            .column("nickname", varchar(45))
+            .meta(METADATA_CHARSET, "latin1")
+            .meta(METADATA_COLLATION, "latin1_swedish_ci")
+            .column("Balance", double(10, 2))
+            .build(),
+    )
+    .set_table(
+        TableBuilder::new("legacy_orders")
+            .column("client_id", int(11))
+            .build(),
+    );
+    db.set_constraint(Constraint::new(
+        "fk_legacy_orders_1",
+        db.table("legacy_orders")
+            .unwrap()
+            .column("client_id")
+            .unwrap(),
+        db.table("clients").unwrap().column("client_id").unwrap(),
+    ));
+
+    let mut linter = Linter::with_default_rules();
+    linter
+        .add_rule(NoLegacyTables)
+        .set_severity("naming_convention", Severity::Warning);
+
+    let findings = linter.run(&db);
+    let rules = findings
+        .iter()
+        .map(|f| f.rule.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(
+        rules,
+        vec![
+            "missing_primary_key",
+            "unindexed_foreign_key",
+            "foreign_key_type_mismatch",
+            "charset_mismatch",
+            "charset_mismatch",
+            "nullable_unique_column",
+            "float_for_money",
+            "naming_convention",
+            "no_legacy_tables",
+        ]
+    );
+
+    assert_eq!(findings[0].severity, Severity::Error);
+    assert_eq!(
+        findings[0].location,
+        LintLocation::Table(Arc::new("legacy_orders".to_string()))
+    );
+    assert_eq!(findings[1].location.to_string(), "legacy_orders.client_id");
+    assert_eq!(
+        findings[5].location,
+        LintLocation::Index(
+            Arc::new("clients".to_string()),
+            Arc::new("email".to_string())
+        )
+    );
+    assert_eq!(findings[7].severity, Severity::Warning);
+    assert_eq!(findings[7].location.to_string(), "clients.Balance");
+    assert_eq!(findings[8].severity, Severity::Warning);
+    assert!(findings[2]
+        .to_string()
+        .starts_with("Error [foreign_key_type_mismatch]"));
+
+    let mut linter = Linter::new();
+    linter.add_rule(MissingPrimaryKey);
+    assert_eq!(
+        linter.rule_names().collect::<Vec<&str>>(),
+        vec!["missing_primary_key"]
+    );
+    assert_eq!(linter.run(&db).len(), 1);
+}
+
+#[test]
+fn test_lint_composite_indexes() {
+    let mut db = Database::new("shop");
+    db.set_table(
+        TableBuilder::new("warehouses")
+            .column("warehouse_id", int(10).unsigned())
+            .primary()
+            .column("region_id", int(10).unsigned())
+            .primary()
+            .build(),
+    )
+    .set_table(
+        TableBuilder::new("stock")
+            .column("stock_id", int(10).unsigned())
+            .primary()
+            .column("warehouse_id", int(10).unsigned())
+            .index("warehouse_product")
+            .column("product_id", int(10).unsigned())
+            .index("warehouse_product")
+            .column("region_id", int(10).unsigned())
+            .column("note", varchar(255))
+            .nullable()
+            .build(),
+    );
+
+    // a plain index as reflected from a row with Non_unique set
+    let non_unique = true;
+    let mut stock = (*db.table("stock").unwrap()).clone();
+    let note = stock.column("note").unwrap();
+    stock.add_index_column("note", note, 1, false, !non_unique);
+    db.set_table(stock);
+
+    let stock = db.table("stock").unwrap();
+    let warehouses = db.table("warehouses").unwrap();
+    let mut constraint = Constraint::new(
+        "fk_stock_1",
+        stock.column("warehouse_id").unwrap(),
+        warehouses.column("warehouse_id").unwrap(),
+    );
+    constraint.add_key_pair(
+        stock.column("region_id").unwrap(),
+        warehouses.column("region_id").unwrap(),
+    );
+    db.set_constraint(constraint);
+
+    let mut linter = Linter::new();
+    linter
+        .add_rule(UnindexedForeignKey)
+        .add_rule(NullableUniqueColumn);
+
+    let findings = linter.run(&db);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].rule, "unindexed_foreign_key");
+    assert_eq!(findings[0].location.to_string(), "stock.region_id");
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "mariadb")]
+
+use database_reflection::adapter::mariadb_innodb::{EnginePolicy, MariadbInnodbReflectionAdapter};
+
+#[test]
+fn test_mariadb_adapter_options() {
+    let mut adapter = MariadbInnodbReflectionAdapter::new("mysql://localhost/test");
+
+    assert_eq!(adapter.engine_policy(), EnginePolicy::Allow);
+    assert!(!adapter.views_as_tables());
+    assert!(!adapter.tables_only());
+    assert_eq!(adapter.max_connections(), 1);
+    assert_eq!(adapter.concurrency(), 1);
+
+    adapter
+        .set_engine_policy(EnginePolicy::Reject)
+        .set_views_as_tables(true)
+        .set_tables_only(true)
+        .set_max_connections(8)
+        .set_concurrency(0);
+
+    assert_eq!(adapter.engine_policy(), EnginePolicy::Reject);
+    assert!(adapter.views_as_tables());
+    assert!(adapter.tables_only());
+    assert_eq!(adapter.max_connections(), 8);
+    assert_eq!(adapter.concurrency(), 1);
+    assert!(adapter.warnings().is_empty());
+}
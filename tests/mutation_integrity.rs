@@ -0,0 +1,164 @@
+use database_reflection::metadata::consts::METADATA_FLAG_PRIMARY;
+use database_reflection::metadata::WithMetadata;
+use database_reflection::reflection::{
+    CheckConstraint, Column, Constraint, Database, Index, SqlDatatype, SqlSigned, Table, Trigger,
+};
+use std::sync::Arc;
+
+#[test]
+fn test_mutation_integrity() {
+    let mut clients = Table::new("clients");
+    clients
+        .set_column(
+            Column::new("clients", "id", SqlDatatype::Int(10, SqlSigned::Unsigned))
+                .set_meta_flag(METADATA_FLAG_PRIMARY)
+                .to_owned(),
+        )
+        .set_column(Column::new("clients", "email", SqlDatatype::Varchar(255)))
+        .set_column(Column::new(
+            "clients",
+            "age",
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        ));
+    let email = clients.column("email").unwrap();
+    clients
+        .set_index(Index::new(
+            "PRIMARY",
+            clients.column("id").unwrap(),
+            true,
+            true,
+        ))
+        .set_index(Index::new("uq_email", email, false, true))
+        .set_check_constraint(
+            CheckConstraint::new("age", "clients", "`age` > 17")
+                .set_column(Some("age"))
+                .to_owned(),
+        );
+
+    let mut orders = Table::new("orders");
+    orders
+        .set_column(Column::new(
+            "orders",
+            "id",
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        ))
+        .set_column(Column::new(
+            "orders",
+            "client_id",
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        ));
+
+    let mut db = Database::new("shop");
+    db.set_table(clients)
+        .set_table(orders)
+        .set_trigger(Trigger::new(
+            "trg_clients",
+            "clients",
+            "BEFORE",
+            "INSERT",
+            "SET NEW.email = LOWER(NEW.email)",
+        ));
+    db.set_constraint(Constraint::new(
+        "fk_orders_clients",
+        db.table("orders").unwrap().column("client_id").unwrap(),
+        db.table("clients").unwrap().column("id").unwrap(),
+    ));
+
+    // table level renames keep order and references
+    let mut table = (*db.table("clients").unwrap()).clone();
+    assert!(table.rename_column("email", "mail").is_some());
+    assert!(table.rename_column("id", "age").is_none());
+    assert_eq!(
+        table
+            .columns()
+            .map(|(k, _)| k.to_string())
+            .collect::<Vec<String>>(),
+        vec!["id", "mail", "age"]
+    );
+    assert_eq!(
+        table.index("uq_email").unwrap().column().name().as_str(),
+        "mail"
+    );
+    assert!(table.rename_column("age", "years").is_some());
+    assert!(table.check_constraint("age").is_none());
+    assert_eq!(
+        table.check_constraint("years").unwrap().expression(),
+        "`years` > 17"
+    );
+    assert_eq!(
+        table.check_constraint("years").unwrap().column(),
+        Some(Arc::new("years".to_string()))
+    );
+    assert!(table.rename_column("years", "age").is_some());
+    assert!(table.rename_index("uq_email", "uq_mail"));
+    assert!(!table.rename_index("missing", "uq_other"));
+    assert!(table.index("uq_email").is_none());
+    assert_eq!(table.indexes().next().unwrap().0.as_str(), "PRIMARY");
+    assert!(table.rename_check_constraint("age", "adult"));
+    assert!(table.remove_column("age").is_some());
+    assert!(table.check_constraint("adult").is_none());
+    assert!(table.remove_index("PRIMARY").is_some());
+    assert_eq!(table.primary_key_count(), 0);
+    table.rename("customers");
+    assert_eq!(table.column("mail").unwrap().table().as_str(), "customers");
+    assert_eq!(
+        table.index("uq_mail").unwrap().column().table().as_str(),
+        "customers"
+    );
+
+    // database level renames relink constraints and triggers
+    assert!(db.rename_table("clients", "orders").is_none());
+    assert!(db.rename_table("clients", "customers").is_some());
+    assert_eq!(db.tables().next().unwrap().0.as_str(), "customers");
+    assert_eq!(
+        db.trigger("trg_clients").unwrap().table().as_str(),
+        "customers"
+    );
+    let constraint = db.constraint("fk_orders_clients").unwrap();
+    assert_eq!(constraint.foreign().table().as_str(), "customers");
+    assert!(Arc::ptr_eq(
+        &constraint.key_pairs().next().unwrap().foreign,
+        &db.table("customers").unwrap().column("id").unwrap()
+    ));
+
+    assert!(db.rename_column("customers", "id", "customer_id").is_some());
+    assert_eq!(
+        db.table("customers")
+            .unwrap()
+            .primary_key()
+            .unwrap()
+            .as_str(),
+        "customer_id"
+    );
+    assert_eq!(
+        db.constraint("fk_orders_clients")
+            .unwrap()
+            .foreign()
+            .name()
+            .as_str(),
+        "customer_id"
+    );
+
+    assert!(db
+        .rename_constraint("fk_orders_clients", "fk_orders_customers")
+        .is_some());
+    assert!(db.constraint("fk_orders_clients").is_none());
+    assert_eq!(
+        db.constraint("fk_orders_customers")
+            .unwrap()
+            .name()
+            .as_str(),
+        "fk_orders_customers"
+    );
+
+    assert!(db.rename_index("customers", "uq_email", "uq_customer_email"));
+    assert!(db.remove_index("customers", "uq_customer_email").is_some());
+
+    assert!(db.remove_column("orders", "client_id").is_some());
+    assert_eq!(db.constraints().len(), 0);
+
+    assert!(db.remove_table("customers").is_some());
+    assert!(db.table("customers").is_none());
+    assert_eq!(db.triggers().len(), 0);
+    assert_eq!(db.tables().len(), 1);
+}
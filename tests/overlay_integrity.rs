@@ -0,0 +1,177 @@
+use database_reflection::builder::types::{int, varchar};
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::metadata::{Overlay, OverlayError, WithMetadata};
+use serde_json::json;
+use std::sync::Arc;
+
+#[test]
+fn test_overlay_integrity() {
+    let mut db = DatabaseBuilder::new("test")
+        .table("clients", |t| {
+            t.column("client_id", int(10).unsigned())
+                .primary()
+                .column("email", varchar(255))
+                .unique()
+                .meta("pii", "legacy")
+        })
+        .table("orders", |t| {
+            t.column("order_id", int(10).unsigned())
+                .primary()
+                .column("client_id", int(10).unsigned())
+                .references("clients.client_id")
+        })
+        .build()
+        .unwrap();
+
+    let overlay = Overlay::from_json(
+        r#"{
+            "clients": { "owner": "crm" },
+            "clients.email": { "pii": ["contact"], "display_name": "E-mail" },
+            "clients.client_id": { "deprecated": true }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(overlay.entries().len(), 3);
+    overlay.apply(&mut db).unwrap();
+
+    let clients = db.table("clients").unwrap();
+    assert_eq!(clients.meta("owner"), Some("crm".to_string()));
+
+    let email = clients.column("email").unwrap();
+    assert_eq!(
+        email.meta_as::<Vec<String>>("pii").unwrap(),
+        Some(vec!["contact".to_string()])
+    );
+    assert_eq!(email.meta("display_name"), Some("E-mail".to_string()));
+    assert!(email.unique());
+
+    // constraints and indexes follow the annotated columns
+    let client_id = clients.column("client_id").unwrap();
+    assert_eq!(client_id.meta_value("deprecated"), Some(json!(true)));
+    let fk = db.constraint("fk_orders_1").unwrap();
+    assert!(Arc::ptr_eq(
+        &fk.key_pairs().next().unwrap().foreign,
+        &client_id
+    ));
+    assert!(fk.foreign().meta_flag("deprecated"));
+    assert!(std::ptr::eq(
+        clients.index("email").unwrap().column(),
+        &*email
+    ));
+
+    // stale entries are reported and nothing is merged
+    let mut stale = Overlay::new();
+    stale
+        .set("orders", "owner", "billing")
+        .set("invoices", "owner", "billing")
+        .set("orders.total", "pii", json!(false));
+    assert_eq!(
+        stale.apply(&mut db).unwrap_err(),
+        vec![
+            OverlayError::MissingTable {
+                table: "invoices".to_string()
+            },
+            OverlayError::MissingColumn {
+                table: "orders".to_string(),
+                column: "total".to_string()
+            },
+        ]
+    );
+    assert!(db.table("orders").unwrap().meta("owner").is_none());
+
+    // keys backed by typed fields are reserved
+    let mut reserved = Overlay::new();
+    reserved
+        .set("clients.email", "nullable", json!(true))
+        .set("clients", "charset", "latin1");
+    assert_eq!(
+        reserved.apply(&mut db).unwrap_err(),
+        vec![
+            OverlayError::ReservedKey {
+                target: "clients.email".to_string(),
+                key: "nullable".to_string()
+            },
+            OverlayError::ReservedKey {
+                target: "clients".to_string(),
+                key: "charset".to_string()
+            },
+        ]
+    );
+    assert!(!db
+        .table("clients")
+        .unwrap()
+        .column("email")
+        .unwrap()
+        .nullable());
+
+    // so is the unsigned flag, which describes the column type
+    let mut unsigned = Overlay::new();
+    unsigned.set("orders.client_id", "unsigned", json!(false));
+    assert_eq!(
+        unsigned.apply(&mut db).unwrap_err(),
+        vec![OverlayError::ReservedKey {
+            target: "orders.client_id".to_string(),
+            key: "unsigned".to_string()
+        }]
+    );
+    assert!(db
+        .table("orders")
+        .unwrap()
+        .column("client_id")
+        .unwrap()
+        .meta_flag("unsigned"));
+
+    // files are parsed by extension
+    let dir = std::env::temp_dir().join(format!("overlay_integrity_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("overlay.json");
+    std::fs::write(&path, r#"{ "orders": { "owner": "billing" } }"#).unwrap();
+    Overlay::load(&path).unwrap().apply(&mut db).unwrap();
+    assert_eq!(
+        db.table("orders").unwrap().meta("owner"),
+        Some("billing".to_string())
+    );
+
+    let path = dir.join("overlay.ini");
+    std::fs::write(&path, "").unwrap();
+    assert_eq!(
+        Overlay::load(&path).unwrap_err(),
+        OverlayError::UnsupportedFormat("ini".to_string())
+    );
+
+    #[cfg(feature = "yaml")]
+    {
+        let overlay = Overlay::from_yaml("orders.client_id:\n  widgets: [select]\n").unwrap();
+        overlay.apply(&mut db).unwrap();
+        assert_eq!(
+            db.table("orders")
+                .unwrap()
+                .column("client_id")
+                .unwrap()
+                .meta_as::<Vec<String>>("widgets")
+                .unwrap(),
+            Some(vec!["select".to_string()])
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    {
+        let overlay = Overlay::from_toml("[\"orders.order_id\"]\nlabel = \"Order\"\n").unwrap();
+        overlay.apply(&mut db).unwrap();
+        assert_eq!(
+            db.table("orders")
+                .unwrap()
+                .column("order_id")
+                .unwrap()
+                .meta("label"),
+            Some("Order".to_string())
+        );
+        assert!(matches!(
+            Overlay::from_toml("[orders"),
+            Err(OverlayError::Parse(_))
+        ));
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "mariadb")]
+
+use database_reflection::adapter::reflection_adapter::{
+    ReflectionAdapter, ReflectionAdapterError, ReflectionAdapterUninitialized,
+};
+use database_reflection::reflection::{Database, Table};
+use sqlx::MySql;
+
+/// Adapter implementing only the required methods
+struct MinimalAdapter;
+
+impl ReflectionAdapterUninitialized<MySql> for MinimalAdapter {
+    type ValidAdapter = MinimalAdapter;
+
+    fn set_connection_string(&mut self, _connection_string: &str) {}
+
+    async fn connect(self) -> Result<MinimalAdapter, ReflectionAdapterError> {
+        Ok(self)
+    }
+}
+
+impl ReflectionAdapter<MySql> for MinimalAdapter {
+    type InvalidAdapter = MinimalAdapter;
+
+    async fn disconnect(self) -> Result<MinimalAdapter, ReflectionAdapterError> {
+        Ok(self)
+    }
+
+    async fn set_database_name(
+        &mut self,
+        _database_name: &str,
+    ) -> Result<(), ReflectionAdapterError> {
+        Ok(())
+    }
+
+    fn get_database_name(&self) -> &str {
+        "minimal"
+    }
+
+    async fn list_database_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
+        Ok(vec!["minimal".to_string()])
+    }
+
+    async fn list_table_names(&self) -> Result<Vec<String>, ReflectionAdapterError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_table_reflection(
+        &self,
+        table_name: &str,
+    ) -> Result<Table, ReflectionAdapterError> {
+        Ok(Table::new(table_name))
+    }
+
+    async fn get_reflection(&self) -> Result<Database, ReflectionAdapterError> {
+        Ok(Database::new("minimal"))
+    }
+}
+
+#[test]
+fn test_reflection_adapter_defaults() {
+    futures::executor::block_on(async {
+        let mut adapter = MinimalAdapter.connect().await.unwrap();
+        adapter.set_query_timeout(None);
+        adapter.set_cancellation_token(None);
+        adapter.set_observer(None);
+
+        assert!(matches!(
+            adapter.list_view_names().await,
+            Err(ReflectionAdapterError::Unsupported(operation)) if operation == "list_view_names"
+        ));
+        assert!(matches!(
+            adapter.refresh_reflection(&Database::new("minimal")).await,
+            Err(ReflectionAdapterError::Unsupported(_))
+        ));
+        assert!(matches!(
+            adapter.get_catalog_reflection(&[]).await,
+            Err(ReflectionAdapterError::Unsupported(_))
+        ));
+        assert_eq!(adapter.get_reflection().await.unwrap().name(), "minimal");
+    });
+}
@@ -0,0 +1,56 @@
+use database_reflection::adapter::reflection_adapter::{
+    CancellationToken, ReflectionAdapterError, ReflectionEvent, ReflectionObserver, TableChange,
+};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+#[test]
+fn test_reflection_progress_integrity() {
+    let token = CancellationToken::new();
+    let shared = token.clone();
+    assert!(!token.is_cancelled());
+    shared.cancel();
+    assert!(token.is_cancelled());
+
+    // waiting on a token wakes up once it is cancelled
+    let waiting = CancellationToken::new();
+    let mut cancelled = pin!(waiting.cancelled());
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(cancelled.as_mut().poll(&mut cx), Poll::Pending);
+    waiting.clone().cancel();
+    assert_eq!(cancelled.as_mut().poll(&mut cx), Poll::Ready(()));
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let observer = ReflectionObserver::new(move |event| sink.lock().unwrap().push(event.clone()));
+    observer.notify(&ReflectionEvent::TableStarted("products".to_string()));
+    observer
+        .clone()
+        .notify(&ReflectionEvent::TableFinished("products".to_string()));
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            ReflectionEvent::TableStarted("products".to_string()),
+            ReflectionEvent::TableFinished("products".to_string()),
+        ]
+    );
+
+    assert_eq!(ReflectionAdapterError::Cancelled.to_string(), "Cancelled");
+    assert_eq!(
+        ReflectionAdapterError::Unsupported("list_view_names".to_string()).to_string(),
+        "Unsupported: list_view_names is not implemented by this adapter"
+    );
+    assert!(matches!(
+        ReflectionAdapterError::Timeout(Duration::from_secs(5)),
+        ReflectionAdapterError::Timeout(d) if d == Duration::from_secs(5)
+    ));
+
+    assert_eq!(
+        TableChange::Dropped(Arc::new("legacy".to_string())).table_name(),
+        Arc::new("legacy".to_string())
+    );
+}
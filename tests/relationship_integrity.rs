@@ -0,0 +1,210 @@
+use database_reflection::builder::types::{int, varchar};
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::graph::{RelationshipKind, Relationships};
+use database_reflection::reflection::{Column, Constraint, Database, Table};
+use std::sync::Arc;
+
+#[test]
+fn test_relationship_integrity() {
+    let db = DatabaseBuilder::new("shop")
+        .table("clients", |t| {
+            t.column("client_id", int(10).unsigned()).primary()
+        })
+        .table("client_profiles", |t| {
+            t.column("client_profile_id", int(10).unsigned())
+                .primary()
+                .column("client_id", int(10).unsigned())
+                .unique()
+                .nullable()
+                .references("clients.client_id")
+        })
+        .table("client_tokens", |t| {
+            t.column("client_token_id", int(10).unsigned())
+                .primary()
+                .column("client_id", int(10).unsigned())
+                .references("clients.client_id")
+                .column("auth_token", varchar(64))
+        })
+        .table("products", |t| {
+            t.column("product_id", int(10).unsigned()).primary()
+        })
+        .table("client_products", |t| {
+            t.column("client_product_id", int(10).unsigned())
+                .primary()
+                .column("client_id", int(10).unsigned())
+                .references("clients.client_id")
+                .column("product_id", int(10).unsigned())
+                .references("products.product_id")
+        })
+        .table("transfers", |t| {
+            t.column("transfer_id", int(10).unsigned())
+                .primary()
+                .column("sender_id", int(10).unsigned())
+                .references("clients.client_id")
+                .column("receiver_id", int(10).unsigned())
+                .references("clients.client_id")
+                .column("amount", int(10))
+        })
+        .build()
+        .unwrap();
+
+    let relationships = Relationships::new(&db);
+
+    let profile = relationships
+        .relationship("client_profiles", "client")
+        .unwrap();
+    assert_eq!(profile.kind, RelationshipKind::OneToOne);
+    assert!(profile.optional);
+    let profile = relationships
+        .relationship("clients", "client_profile")
+        .unwrap();
+    assert_eq!(profile.kind, RelationshipKind::OneToOne);
+
+    let token = relationships
+        .relationship("client_tokens", "client")
+        .unwrap();
+    assert_eq!(token.kind, RelationshipKind::ManyToOne);
+    assert!(!token.optional);
+    assert_eq!(token.related_table, Arc::new("clients".to_string()));
+    let tokens = relationships
+        .relationship("clients", "client_tokens")
+        .unwrap();
+    assert_eq!(tokens.kind, RelationshipKind::OneToMany);
+
+    let products = relationships.relationship("clients", "products").unwrap();
+    assert_eq!(products.kind, RelationshipKind::ManyToMany);
+    assert_eq!(
+        products.through,
+        Some(Arc::new("client_products".to_string()))
+    );
+    assert_eq!(
+        products.constraints,
+        vec![
+            Arc::new("fk_client_products_1".to_string()),
+            Arc::new("fk_client_products_2".to_string())
+        ]
+    );
+    let clients = relationships.relationship("products", "clients").unwrap();
+    assert_eq!(clients.kind, RelationshipKind::ManyToMany);
+
+    // transfers is not a junction because of its extra column, and its names are disambiguated
+    assert!(relationships.relationship("transfers", "sender").is_some());
+    assert!(relationships
+        .relationship("transfers", "receiver")
+        .is_some());
+    assert!(relationships
+        .relationship("clients", "transfers_by_sender")
+        .is_some());
+    assert!(relationships
+        .relationship("clients", "transfers_by_receiver")
+        .is_some());
+    assert!(relationships.relationship("clients", "clients").is_none());
+
+    assert_eq!(relationships.by_table("clients").len(), 6);
+    assert_eq!(relationships.iter().len(), 14);
+}
+
+/// Build a table the way the adapter does, one `SHOW INDEXES` row
+/// of key name, sequence, column and `Non_unique` at a time
+fn reflected_table(name: &str, columns: &[&str], rows: &[(&str, usize, &str, bool)]) -> Table {
+    let mut table = Table::new(name);
+    for column in columns {
+        let mut column = Column::new(name, column, int(10));
+        if rows
+            .iter()
+            .any(|r| r.0 == "PRIMARY" && r.2 == column.name().as_str())
+        {
+            column.set_primary(true);
+        }
+        table.set_column(column);
+    }
+
+    for (key_name, sequence, column_name, non_unique) in rows {
+        let column = table.column(column_name).unwrap();
+        table.add_index_column(
+            key_name,
+            column,
+            *sequence,
+            *key_name == "PRIMARY",
+            !non_unique,
+        );
+    }
+
+    table
+}
+
+#[test]
+fn test_relationship_from_reflected_indexes() {
+    let mut db = Database::new("shop");
+    db.set_table(reflected_table(
+        "clients",
+        &["client_id"],
+        &[("PRIMARY", 1, "client_id", false)],
+    ));
+    db.set_table(reflected_table(
+        "client_tokens",
+        &["client_token_id", "client_id"],
+        &[
+            ("PRIMARY", 1, "client_token_id", false),
+            ("client_id", 1, "client_id", true),
+        ],
+    ));
+    db.set_table(reflected_table(
+        "client_profiles",
+        &["client_profile_id", "client_id"],
+        &[
+            ("PRIMARY", 1, "client_profile_id", false),
+            ("client_id", 1, "client_id", false),
+        ],
+    ));
+    db.set_table(reflected_table(
+        "client_devices",
+        &["client_device_id", "client_id", "serial"],
+        &[
+            ("PRIMARY", 1, "client_device_id", false),
+            ("serial", 1, "client_id", false),
+            ("serial", 2, "serial", false),
+        ],
+    ));
+
+    for table_name in ["client_tokens", "client_profiles", "client_devices"] {
+        let local = db.table(table_name).unwrap().column("client_id").unwrap();
+        let foreign = db.table("clients").unwrap().column("client_id").unwrap();
+        db.set_constraint(Constraint::new(
+            format!("fk_{}_1", table_name),
+            local,
+            foreign,
+        ));
+    }
+
+    let devices = db.table("client_devices").unwrap();
+    assert_eq!(
+        devices.index("serial").unwrap().column().name().as_str(),
+        "client_id"
+    );
+    assert_eq!(devices.index("serial").unwrap().column_count(), 2);
+
+    let relationships = Relationships::new(&db);
+    assert_eq!(
+        relationships
+            .relationship("client_tokens", "client")
+            .unwrap()
+            .kind,
+        RelationshipKind::ManyToOne
+    );
+    assert_eq!(
+        relationships
+            .relationship("client_profiles", "client")
+            .unwrap()
+            .kind,
+        RelationshipKind::OneToOne
+    );
+    // a unique index over more columns doesn't make the first one unique
+    assert_eq!(
+        relationships
+            .relationship("client_devices", "client")
+            .unwrap()
+            .kind,
+        RelationshipKind::ManyToOne
+    );
+}
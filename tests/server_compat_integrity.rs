@@ -0,0 +1,161 @@
+use database_reflection::adapter::server::{ServerFlavor, ServerInfo};
+use database_reflection::reflection::{DefaultValue, SqlDatatype, SqlSigned};
+use serde_json::Value;
+
+#[test]
+fn test_server_compat_integrity() {
+    let mariadb = ServerInfo::parse("10.11.6-MariaDB-1:10.11.6+maria~ubu2204-log");
+    assert_eq!(mariadb.flavor(), ServerFlavor::MariaDb);
+    assert_eq!(mariadb.version_numbers(), (10, 11, 6));
+    assert_eq!(
+        ServerInfo::parse("5.5.5-10.3.39-MariaDB").version_numbers(),
+        (10, 3, 39)
+    );
+
+    let mysql = ServerInfo::parse("8.0.36");
+    assert!(mysql.is_mysql());
+    assert!(mysql.at_least(8, 0, 16));
+    assert!(!mysql.at_least(8, 1, 0));
+    assert_eq!(mysql.to_string(), "MySQL 8.0.36");
+
+    // width-less integers get the MariaDB display width
+    assert_eq!(
+        SqlDatatype::try_from("int unsigned"),
+        Ok(SqlDatatype::Int(10, SqlSigned::Unsigned))
+    );
+    assert_eq!(
+        SqlDatatype::try_from("bigint"),
+        Ok(SqlDatatype::Bigint(20, SqlSigned::Signed))
+    );
+    assert_eq!(
+        SqlDatatype::try_from("tinyint"),
+        Ok(SqlDatatype::Tinyint(4, SqlSigned::Signed))
+    );
+
+    // extra
+    assert_eq!(
+        mysql.normalize_extra("DEFAULT_GENERATED on update CURRENT_TIMESTAMP"),
+        "on update current_timestamp()"
+    );
+    assert_eq!(
+        mysql.normalize_extra("on update CURRENT_TIMESTAMP(3)"),
+        "on update current_timestamp(3)"
+    );
+    assert_eq!(mysql.normalize_extra("auto_increment"), "auto_increment");
+    assert_eq!(
+        mariadb.normalize_extra("on update current_timestamp()"),
+        "on update current_timestamp()"
+    );
+
+    // defaults end up the same as on MariaDB
+    let varchar = SqlDatatype::Varchar(255);
+    let int = SqlDatatype::Int(10, SqlSigned::Unsigned);
+
+    let pairs = [
+        (
+            mysql.normalize_default(
+                Some("CURRENT_TIMESTAMP"),
+                "DEFAULT_GENERATED",
+                false,
+                &SqlDatatype::Timestamp,
+            ),
+            mariadb.normalize_default(
+                Some("current_timestamp()"),
+                "",
+                false,
+                &SqlDatatype::Timestamp,
+            ),
+        ),
+        (
+            mysql.normalize_default(Some("it's"), "", false, &varchar),
+            mariadb.normalize_default(Some("'it''s'"), "", false, &varchar),
+        ),
+        (
+            mysql.normalize_default(Some("5"), "", false, &varchar),
+            mariadb.normalize_default(Some("'5'"), "", false, &varchar),
+        ),
+        (
+            mysql.normalize_default(Some("5"), "", false, &int),
+            mariadb.normalize_default(Some("5"), "", false, &int),
+        ),
+        (
+            mysql.normalize_default(None, "", true, &varchar),
+            mariadb.normalize_default(Some("NULL"), "", true, &varchar),
+        ),
+        (
+            mysql.normalize_default(None, "", false, &varchar),
+            mariadb.normalize_default(None, "", false, &varchar),
+        ),
+        (
+            mysql.normalize_default(None, "auto_increment", true, &int),
+            mariadb.normalize_default(None, "auto_increment", true, &int),
+        ),
+    ];
+
+    for (mysql_default, mariadb_default) in pairs {
+        assert_eq!(mysql_default, mariadb_default);
+    }
+
+    assert_eq!(
+        mysql
+            .normalize_default(
+                Some("CURRENT_TIMESTAMP"),
+                "DEFAULT_GENERATED",
+                false,
+                &SqlDatatype::Timestamp
+            )
+            .map(|d| DefaultValue::from_sql(&d, &SqlDatatype::Timestamp)),
+        Some(DefaultValue::Expression("current_timestamp()".to_string()))
+    );
+    assert_eq!(
+        mysql
+            .normalize_default(Some("5"), "", false, &varchar)
+            .map(|d| DefaultValue::from_sql(&d, &varchar)),
+        Some(DefaultValue::Value(Value::String("5".to_string())))
+    );
+
+    // MariaDB before 10.2.7 reports literals unquoted like MySQL
+    let old_mariadb = ServerInfo::parse("5.5.5-10.1.48-MariaDB");
+    assert!(old_mariadb.is_mariadb());
+    assert_eq!(
+        old_mariadb.normalize_default(Some("5"), "", false, &varchar),
+        mariadb.normalize_default(Some("'5'"), "", false, &varchar)
+    );
+    assert_eq!(
+        old_mariadb
+            .normalize_default(Some("pending"), "", false, &varchar)
+            .map(|d| DefaultValue::from_sql(&d, &varchar)),
+        Some(DefaultValue::Value(Value::String("pending".to_string())))
+    );
+    assert_eq!(
+        old_mariadb.normalize_default(Some("5"), "", false, &int),
+        Some("5".to_string())
+    );
+    assert_eq!(
+        old_mariadb.normalize_default(None, "", true, &varchar),
+        Some("NULL".to_string())
+    );
+    assert_eq!(
+        old_mariadb
+            .normalize_default(
+                Some("CURRENT_TIMESTAMP"),
+                "",
+                false,
+                &SqlDatatype::Timestamp
+            )
+            .map(|d| DefaultValue::from_sql(&d, &SqlDatatype::Timestamp)),
+        Some(DefaultValue::Expression("current_timestamp()".to_string()))
+    );
+    assert_eq!(
+        old_mariadb.normalize_extra("on update CURRENT_TIMESTAMP"),
+        "on update current_timestamp()"
+    );
+
+    // check clauses
+    assert_eq!(mysql.normalize_check_clause("(`price` > 0)"), "`price` > 0");
+    assert_eq!(
+        mysql.normalize_check_clause("(`a` > 0) and (`b` > 0)"),
+        "(`a` > 0) and (`b` > 0)"
+    );
+    assert_eq!(mariadb.normalize_check_clause("`price` > 0"), "`price` > 0");
+}
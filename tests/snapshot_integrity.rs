@@ -0,0 +1,151 @@
+use database_reflection::builder::types::{int, varchar};
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::metadata::consts::*;
+use database_reflection::metadata::WithMetadata;
+use database_reflection::snapshot::{Snapshot, SnapshotError, SNAPSHOT_VERSION};
+use std::sync::Arc;
+
+#[test]
+fn test_snapshot_integrity() {
+    let mut db = DatabaseBuilder::new("test")
+        .charset("utf8mb4", "utf8mb4_unicode_ci")
+        .meta("owner", "billing")
+        .meta("audited", "1")
+        .table("products", |t| {
+            t.column("product_id", int(10).unsigned())
+                .primary()
+                .column("name", varchar(255))
+                .nullable()
+        })
+        .table("clients", |t| {
+            t.column("client_id", int(10).unsigned())
+                .primary()
+                .auto_increment()
+                .column("email", varchar(255))
+                .unique()
+        })
+        .table("client_products", |t| {
+            t.column("client_product_id", int(10).unsigned())
+                .primary()
+                .column("product_id", int(10).unsigned())
+                .index("fk_products_idx")
+                .references_as("fk_products", "products.product_id")
+                .column("client_id", int(10).unsigned())
+                .index("fk_clients_idx")
+                .references_as("fk_clients", "clients.client_id")
+                .on_delete(METADATA_CASCADE)
+        })
+        .build()
+        .unwrap();
+
+    let mut clients = (*db.table("clients").unwrap()).clone();
+    clients.set_auto_increment(Some(42));
+    db.set_table(clients);
+
+    let snapshot = Snapshot::new(&db);
+    assert_eq!(snapshot.version(), SNAPSHOT_VERSION);
+    assert_eq!(snapshot.name(), "test");
+
+    let json = serde_json::to_string_pretty(&snapshot).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string_pretty(&Snapshot::new(&db)).unwrap()
+    );
+
+    // tables and constraints sorted by name, columns referenced by name
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let names = |key: &str| {
+        value[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["name"].as_str().unwrap().to_string())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(names("tables"), ["client_products", "clients", "products"]);
+    assert_eq!(names("constraints"), ["fk_clients", "fk_products"]);
+    assert_eq!(
+        value["constraints"][0]["key_pairs"][0]["local"],
+        "client_products.client_id"
+    );
+    assert_eq!(
+        value["constraints"][0]["key_pairs"][0]["foreign"],
+        "clients.client_id"
+    );
+    assert_eq!(value["tables"][0]["indexes"][1]["column"], "client_id");
+    assert!(value["tables"][1].get("auto_increment").is_none());
+
+    let metadata_keys = value["metadata"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect::<Vec<String>>();
+    assert_eq!(metadata_keys, ["audited", "owner"]);
+    assert_eq!(value["charset"], "utf8mb4");
+    assert_eq!(value["tables"][2]["columns"][1]["nullable"], true);
+
+    // loading shares the table columns with indexes and constraints
+    let restored = serde_json::from_str::<Snapshot>(&json)
+        .unwrap()
+        .into_database()
+        .unwrap();
+    assert!(restored.validate().is_ok());
+    assert_eq!(restored.tables().len(), 3);
+    assert_eq!(restored.meta("owner"), Some("billing".to_string()));
+
+    let client_products = restored.table("client_products").unwrap();
+    let client_id = client_products.column("client_id").unwrap();
+    assert!(std::ptr::eq(
+        client_products.index("fk_clients_idx").unwrap().column(),
+        &*client_id
+    ));
+
+    let fk_clients = restored.constraint("fk_clients").unwrap();
+    let key_pair = fk_clients.key_pairs().next().unwrap();
+    assert!(Arc::ptr_eq(&key_pair.local, &client_id));
+    assert!(Arc::ptr_eq(
+        &key_pair.foreign,
+        &restored
+            .table("clients")
+            .unwrap()
+            .column("client_id")
+            .unwrap()
+    ));
+    assert_eq!(
+        fk_clients.meta(METADATA_ON_DELETE),
+        Some(METADATA_CASCADE.to_string())
+    );
+    assert_eq!(
+        restored.table("clients").unwrap().primary_key(),
+        Some(Arc::new("client_id".to_string()))
+    );
+
+    // a reloaded snapshot serializes back to the same text
+    assert_eq!(
+        serde_json::to_string_pretty(&Snapshot::new(&restored)).unwrap(),
+        json
+    );
+
+    // unknown versions and dangling references are refused
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["version"] = serde_json::Value::from(SNAPSHOT_VERSION + 1);
+    assert_eq!(
+        serde_json::from_value::<Snapshot>(value)
+            .unwrap()
+            .into_database()
+            .unwrap_err(),
+        SnapshotError::UnsupportedVersion(SNAPSHOT_VERSION + 1)
+    );
+
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["constraints"][0]["key_pairs"][0]["foreign"] =
+        serde_json::Value::from("clients.missing_id");
+    assert!(matches!(
+        serde_json::from_value::<Snapshot>(value)
+            .unwrap()
+            .into_database()
+            .unwrap_err(),
+        SnapshotError::Integrity(issues) if issues.len() == 1
+    ));
+}
@@ -0,0 +1,124 @@
+use database_reflection::reflection::{
+    Database, Event, ParameterMode, Routine, RoutineKind, SqlDatatype, SqlSigned, Trigger,
+};
+
+#[test]
+fn test_stored_program_integrity() {
+    let trigger = Trigger::new(
+        "clients_before_insert",
+        "clients",
+        "BEFORE",
+        "INSERT",
+        "SET NEW.email = LOWER(NEW.email)",
+    )
+    .set_action_order(1)
+    .set_definer(Some("root@localhost"))
+    .to_owned();
+
+    assert_eq!(trigger.name(), String::from("clients_before_insert").into());
+    assert_eq!(trigger.table(), String::from("clients").into());
+    assert_eq!(trigger.timing(), "BEFORE");
+    assert_eq!(trigger.event(), "INSERT");
+    assert_eq!(trigger.action_order(), 1);
+    assert_eq!(trigger.definer(), Some("root@localhost"));
+
+    let function = Routine::new("client_count", RoutineKind::Function)
+        .add_parameter("since", None, SqlDatatype::try_from("timestamp").unwrap())
+        .set_returns(Some(SqlDatatype::Int(10, SqlSigned::Unsigned)))
+        .set_definition(Some(
+            "RETURN (SELECT COUNT(*) FROM clients WHERE created > since)",
+        ))
+        .set_deterministic(false)
+        .set_data_access(Some("READS SQL DATA"))
+        .to_owned();
+
+    assert_eq!(function.kind(), &RoutineKind::Function);
+    assert_eq!(function.parameters().len(), 1);
+    assert_eq!(
+        function.returns(),
+        Some(&SqlDatatype::Int(10, SqlSigned::Unsigned))
+    );
+    assert!(!function.deterministic());
+
+    let procedure = Routine::new("verify_client", RoutineKind::Procedure)
+        .add_parameter(
+            "client_id",
+            Some(ParameterMode::In),
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        )
+        .add_parameter(
+            "verified",
+            Some(ParameterMode::Out),
+            SqlDatatype::Tinyint(1, SqlSigned::Unsigned),
+        )
+        .to_owned();
+
+    assert_eq!(procedure.returns(), None);
+    assert_eq!(
+        procedure.parameters().last().unwrap().mode,
+        Some(ParameterMode::Out)
+    );
+
+    let event = Event::new("purge_tokens", "DELETE FROM client_tokens", "RECURRING")
+        .set_interval(Some("1"), Some("DAY"))
+        .set_schedule(Some("2024-01-01 00:00:00"), None::<String>)
+        .set_status(Some("ENABLED"))
+        .set_on_completion(Some("PRESERVE"))
+        .to_owned();
+
+    assert_eq!(event.event_type(), "RECURRING");
+    assert_eq!(event.interval_value(), Some("1"));
+    assert_eq!(event.interval_field(), Some("DAY"));
+    assert_eq!(event.starts(), Some("2024-01-01 00:00:00"));
+    assert_eq!(event.ends(), None);
+
+    let mut db = Database::new("test");
+    db.set_trigger(trigger)
+        .set_routine(function)
+        .set_routine(procedure)
+        .set_event(event);
+
+    assert_eq!(db.triggers_by_table("clients").len(), 1);
+    assert!(db.triggers_by_table("products").is_empty());
+    assert_eq!(db.routines().len(), 2);
+    assert!(db
+        .routine(RoutineKind::Procedure, "verify_client")
+        .is_some());
+    assert!(db.routine(RoutineKind::Function, "verify_client").is_none());
+
+    // a function may share its name with a procedure, unknown types are kept as declared
+    db.set_routine(
+        Routine::new("verify_client", RoutineKind::Function)
+            .add_declared_parameter("payload", None, "json")
+            .set_declared_returns(Some("tinyint(1) unsigned"))
+            .to_owned(),
+    );
+    assert_eq!(db.routines().len(), 3);
+
+    let function = db.routine(RoutineKind::Function, "verify_client").unwrap();
+    let parameter = function.parameters().next().unwrap();
+    assert_eq!(parameter.datatype, None);
+    assert_eq!(parameter.declared_type.as_deref(), Some("json"));
+    assert_eq!(
+        function.returns(),
+        Some(&SqlDatatype::Tinyint(1, SqlSigned::Unsigned))
+    );
+    assert_eq!(function.declared_returns(), Some("tinyint(1) unsigned"));
+    assert!(db.event("purge_tokens").is_some());
+
+    let json = serde_json::to_string(&db).unwrap();
+    let restored: Database = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.routines().len(), 3);
+    assert_eq!(
+        restored
+            .routine(RoutineKind::Function, "verify_client")
+            .unwrap()
+            .parameters()
+            .next()
+            .unwrap()
+            .declared_type
+            .as_deref(),
+        Some("json")
+    );
+    assert_eq!(restored.events().len(), 1);
+}
@@ -0,0 +1,68 @@
+use database_reflection::reflection::{Partition, Table};
+
+#[test]
+fn test_table_integrity() {
+    let mut table = Table::new("events");
+    table
+        .set_comment(Some("Audit trail"))
+        .set_engine(Some("InnoDB"))
+        .set_row_format(Some("Dynamic"))
+        .set_auto_increment(Some(1024))
+        .set_create_options(Some("partitioned"));
+
+    assert_eq!(table.name(), String::from("events").into());
+    assert_eq!(table.comment(), Some("Audit trail"));
+    assert_eq!(table.engine(), Some("InnoDB"));
+    assert_eq!(table.row_format(), Some("Dynamic"));
+    assert_eq!(table.auto_increment(), Some(1024));
+    assert_eq!(table.create_options(), Some("partitioned"));
+    assert!(!table.is_partitioned());
+    assert!(!table.has_change_stamp());
+
+    table
+        .set_create_time(Some("2024-01-01 10:00:00"))
+        .set_update_time(None::<String>)
+        .set_checksum(Some("8243190476"));
+
+    assert!(table.has_change_stamp());
+    assert_eq!(table.create_time(), Some("2024-01-01 10:00:00"));
+    assert_eq!(table.update_time(), None);
+    assert_eq!(table.checksum(), Some("8243190476"));
+
+    table
+        .set_partition(
+            Partition::new("p2023", "RANGE")
+                .set_expression(Some("year(`created`)"))
+                .set_description(Some("2024"))
+                .to_owned(),
+        )
+        .set_partition(
+            Partition::new("pmax", "RANGE")
+                .set_expression(Some("year(`created`)"))
+                .set_description(Some("MAXVALUE"))
+                .add_subpartition("pmaxsp0")
+                .add_subpartition("pmaxsp1")
+                .add_subpartition("pmaxsp0")
+                .to_owned(),
+        );
+
+    assert!(table.is_partitioned());
+    assert_eq!(table.partitions().len(), 2);
+    assert_eq!(table.partition("p2023").unwrap().method(), "RANGE");
+    assert_eq!(
+        table.partition("p2023").unwrap().expression(),
+        Some("year(`created`)")
+    );
+    assert_eq!(
+        table.partition("pmax").unwrap().description(),
+        Some("MAXVALUE")
+    );
+    assert_eq!(table.partition("pmax").unwrap().subpartitions().len(), 2);
+    assert_eq!(table.partition("pmax").unwrap().subpartition_method(), None);
+
+    let json = serde_json::to_string(&table).unwrap();
+    let restored: Table = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.engine(), Some("InnoDB"));
+    assert_eq!(restored.partitions().len(), 2);
+    assert_eq!(restored.checksum(), Some("8243190476"));
+}
@@ -0,0 +1,59 @@
+use database_reflection::adapter::reflection_adapter::{
+    detect_table_changes, TableChange, TableStamp,
+};
+use database_reflection::reflection::{Database, Table};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[test]
+fn test_table_stamp_integrity() {
+    let stamp = |checksum: &str| TableStamp {
+        create_time: Some("2024-01-01 00:00:00".to_string()),
+        update_time: None,
+        checksum: Some(checksum.to_string()),
+    };
+    let stamped = |name: &str, checksum: &str| {
+        Table::new(name)
+            .set_create_time(Some("2024-01-01 00:00:00"))
+            .set_checksum(Some(checksum))
+            .to_owned()
+    };
+
+    let mut previous = Database::new("test");
+    previous
+        .set_table(stamped("clients", "100"))
+        .set_table(stamped("products", "200"))
+        .set_table(stamped("legacy", "300"))
+        .set_table(Table::new("unstamped"));
+
+    let table_names = ["clients", "products", "unstamped", "orders"]
+        .map(String::from)
+        .to_vec();
+    let stamps = HashMap::from([
+        ("clients".to_string(), stamp("100")),
+        ("products".to_string(), stamp("201")),
+        ("unstamped".to_string(), stamp("400")),
+        ("orders".to_string(), stamp("500")),
+    ]);
+
+    assert!(stamp("100").matches(&previous.table("clients").unwrap()));
+    assert!(!stamp("100").matches(&previous.table("unstamped").unwrap()));
+
+    let (changes, reusable) = detect_table_changes(&previous, &table_names, &stamps);
+
+    assert_eq!(reusable, vec!["clients".to_string()]);
+    assert_eq!(
+        changes,
+        vec![
+            TableChange::Modified(Arc::new("products".to_string())),
+            TableChange::Modified(Arc::new("unstamped".to_string())),
+            TableChange::Added(Arc::new("orders".to_string())),
+            TableChange::Dropped(Arc::new("legacy".to_string())),
+        ]
+    );
+
+    // a table missing from the stamps is reflected again
+    let (changes, reusable) = detect_table_changes(&previous, &table_names, &HashMap::new());
+    assert!(reusable.is_empty());
+    assert!(changes.contains(&TableChange::Modified(Arc::new("clients".to_string()))));
+}
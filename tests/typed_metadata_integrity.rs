@@ -0,0 +1,142 @@
+use database_reflection::builder::types::{int, timestamp, varchar};
+use database_reflection::builder::DatabaseBuilder;
+use database_reflection::metadata::consts::*;
+use database_reflection::metadata::WithMetadata;
+use database_reflection::reflection::{Column, Database, SqlDatatype, Table};
+use database_reflection::snapshot::Snapshot;
+
+#[test]
+fn test_typed_metadata_integrity() {
+    let db = DatabaseBuilder::new("test")
+        .charset("utf8mb4", "utf8mb4_unicode_ci")
+        .table("clients", |t| {
+            t.charset("utf8mb4", "utf8mb4_unicode_ci")
+                .column("client_id", int(10).unsigned())
+                .primary()
+                .auto_increment()
+                .column("email", varchar(255))
+                .unique()
+                .nullable()
+                .meta("pii", "email")
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(db.charset(), Some("utf8mb4"));
+    let clients = db.table("clients").unwrap();
+    assert_eq!(clients.collation(), Some("utf8mb4_unicode_ci"));
+
+    let client_id = clients.column("client_id").unwrap();
+    assert!(client_id.primary() && client_id.auto_increment());
+    assert!(!client_id.nullable() && client_id.charset().is_none());
+
+    let email = clients.column("email").unwrap();
+    assert!(email.unique() && email.nullable());
+    assert_eq!(email.charset(), Some("utf8mb4"));
+
+    // well known keys are typed, custom keys stay in the metadata container
+    assert!(email.meta_flag(METADATA_FLAG_NULLABLE));
+    assert_eq!(
+        email.meta(METADATA_COLLATION),
+        Some("utf8mb4_unicode_ci".to_string())
+    );
+    assert_eq!(email.get_metadata().len(), 1);
+    assert_eq!(email.meta("pii"), Some("email".to_string()));
+
+    let mut column = Column::new("clients", "updated", timestamp());
+    column.set_meta_flag(METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP);
+    assert_eq!(column.on_update(), Some("current_timestamp()"));
+    assert!(column.on_update_current_timestamp());
+    assert!(column.meta_flag(METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP));
+    column.set_nullable(true).set_nullable(false);
+    assert!(!column.meta_flag(METADATA_FLAG_NULLABLE));
+
+    // output written before the typed fields existed moves into them on load
+    let mut legacy =
+        serde_json::to_value(Column::new("clients", "name", SqlDatatype::Varchar(45))).unwrap();
+    legacy["metadata"] = serde_json::json!({
+        "nullable": "1",
+        "charset": "latin1",
+        "collation": "latin1_swedish_ci",
+        "owner": "crm"
+    });
+    let legacy: Column = serde_json::from_value(legacy).unwrap();
+    assert!(legacy.nullable());
+    assert_eq!(legacy.charset(), Some("latin1"));
+    assert_eq!(legacy.get_metadata().len(), 1);
+
+    // structured values survive serialization
+    let mut table = (*clients).clone();
+    table.set_meta_value("owners", serde_json::json!(["crm", "billing"]));
+    let table: Table = serde_json::from_str(&serde_json::to_string(&table).unwrap()).unwrap();
+    assert_eq!(
+        table.meta_as::<Vec<String>>("owners").unwrap(),
+        Some(vec![String::from("crm"), String::from("billing")])
+    );
+
+    let restored: Database = serde_json::from_str(&serde_json::to_string(&db).unwrap()).unwrap();
+    assert!(restored
+        .table("clients")
+        .unwrap()
+        .column("email")
+        .unwrap()
+        .unique());
+
+    // snapshots with the typed values in metadata load into the typed fields
+    let mut untyped = serde_json::to_value(Snapshot::new(&db)).unwrap();
+    untyped["metadata"] =
+        serde_json::json!({"charset": "utf8mb4", "collation": "utf8mb4_unicode_ci"});
+    untyped.as_object_mut().unwrap().remove("charset");
+    untyped.as_object_mut().unwrap().remove("collation");
+    let email = &mut untyped["tables"][0]["columns"][1];
+    email.as_object_mut().unwrap().remove("nullable");
+    email["metadata"]["nullable"] = serde_json::Value::from("1");
+
+    let restored = serde_json::from_value::<Snapshot>(untyped)
+        .unwrap()
+        .into_database()
+        .unwrap();
+    assert_eq!(restored.charset(), Some("utf8mb4"));
+    assert!(restored
+        .table("clients")
+        .unwrap()
+        .column("email")
+        .unwrap()
+        .nullable());
+}
+
+#[test]
+fn test_typed_metadata_values() {
+    let mut column = Column::new("clients", "email", varchar(255));
+
+    column.set_meta_value(METADATA_FLAG_NULLABLE, true);
+    assert!(column.nullable());
+    column.set_meta_value(METADATA_FLAG_NULLABLE, false);
+    assert!(!column.nullable());
+
+    column.set_meta_value(METADATA_FLAG_UNIQUE, serde_json::json!(1));
+    assert!(column.unique());
+    column.set_meta_value(METADATA_FLAG_UNIQUE, serde_json::json!(0));
+    assert!(!column.unique());
+
+    column.set_meta_flag(METADATA_FLAG_PRIMARY);
+    assert!(column.primary());
+    column.set_meta_value(METADATA_FLAG_PRIMARY, serde_json::Value::Null);
+    assert!(!column.primary());
+
+    column.set_meta(METADATA_CHARSET, "latin1");
+    assert_eq!(column.charset(), Some("latin1"));
+    column.set_meta_value(METADATA_CHARSET, serde_json::Value::Null);
+    assert_eq!(column.charset(), None);
+    assert_eq!(column.meta(METADATA_CHARSET), None);
+
+    column.set_meta_flag(METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP);
+    column.set_meta_value(METADATA_FLAG_ON_UPDATE_CURRENT_TIMESTAMP, false);
+    assert_eq!(column.on_update(), None);
+    assert!(column.get_metadata().is_empty());
+
+    let mut table = Table::new("clients");
+    table.set_meta(METADATA_COLLATION, "latin1_swedish_ci");
+    table.set_meta_value(METADATA_COLLATION, serde_json::Value::Null);
+    assert_eq!(table.collation(), None);
+}
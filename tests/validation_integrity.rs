@@ -0,0 +1,136 @@
+use database_reflection::adapter::reflection_adapter::ReflectionAdapterError;
+use database_reflection::metadata::consts::METADATA_FLAG_PRIMARY;
+use database_reflection::metadata::WithMetadata;
+use database_reflection::reflection::{
+    CheckConstraint, Column, Constraint, Database, Index, IntegrityIssue, SqlDatatype, SqlSigned,
+    Table, View,
+};
+use std::sync::Arc;
+
+#[test]
+fn test_validation_integrity() {
+    let mut clients = Table::new("clients");
+    clients
+        .set_column(
+            Column::new("clients", "id", SqlDatatype::Int(10, SqlSigned::Unsigned))
+                .set_meta_flag(METADATA_FLAG_PRIMARY)
+                .to_owned(),
+        )
+        .set_column(Column::new("clients", "email", SqlDatatype::Varchar(255)));
+    clients.set_index(Index::new(
+        "PRIMARY",
+        clients.column("id").unwrap(),
+        true,
+        true,
+    ));
+
+    let mut orders = Table::new("orders");
+    orders
+        .set_column(Column::new(
+            "orders",
+            "id",
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        ))
+        .set_column(Column::new(
+            "orders",
+            "client_id",
+            SqlDatatype::Int(10, SqlSigned::Signed),
+        ))
+        .set_column(Column::new("orders", "email", SqlDatatype::Char(64)));
+
+    let mut db = Database::new("shop");
+    db.set_table(clients).set_table(orders);
+    db.set_constraint(Constraint::new(
+        "fk_orders_emails",
+        db.table("orders").unwrap().column("email").unwrap(),
+        db.table("clients").unwrap().column("email").unwrap(),
+    ));
+    assert!(db.validate().is_ok());
+
+    // signed against unsigned
+    db.set_constraint(Constraint::new(
+        "fk_orders_clients",
+        db.table("orders").unwrap().column("client_id").unwrap(),
+        db.table("clients").unwrap().column("id").unwrap(),
+    ));
+    // foreign table does not exist
+    db.set_constraint(Constraint::new(
+        "fk_orders_vendors",
+        db.table("orders").unwrap().column("id").unwrap(),
+        Arc::new(Column::new(
+            "vendors",
+            "id",
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        )),
+    ));
+    db.set_view(View::new("clients", "SELECT 1"));
+
+    let issues = db.validate().unwrap_err();
+    assert!(issues.contains(&IntegrityIssue::DanglingConstraint {
+        constraint: Arc::new("fk_orders_vendors".to_string()),
+        table: Arc::new("vendors".to_string()),
+        column: Arc::new("id".to_string()),
+    }));
+    assert!(issues.iter().any(|i| matches!(
+        i,
+        IntegrityIssue::ConstraintTypeMismatch { constraint, .. } if constraint.as_str() == "fk_orders_clients"
+    )));
+    assert!(issues.contains(&IntegrityIssue::DuplicateName {
+        kind: "table or view",
+        name: Arc::new("clients".to_string()),
+    }));
+    assert_eq!(issues.len(), 3);
+
+    let error: ReflectionAdapterError = issues.into();
+    assert!(matches!(error, ReflectionAdapterError::IntegrityError(_)));
+
+    // table level issues
+    let mut table = Table::new("products");
+    table
+        .set_column(
+            Column::new("products", "id", SqlDatatype::Int(10, SqlSigned::Unsigned))
+                .set_meta_flag(METADATA_FLAG_PRIMARY)
+                .to_owned(),
+        )
+        .set_column(Column::new("items", "sku", SqlDatatype::Varchar(32)))
+        .set_index(Index::new(
+            "idx_price",
+            Arc::new(Column::new(
+                "products",
+                "price",
+                SqlDatatype::Decimal(10, 2, SqlSigned::Unsigned),
+            )),
+            false,
+            false,
+        ))
+        .set_check_constraint(
+            CheckConstraint::new("chk_price", "products", "`price` > 0")
+                .set_column(Some("price"))
+                .to_owned(),
+        );
+    let issues = table.validate().unwrap_err();
+    assert_eq!(issues.len(), 3);
+    assert_eq!(
+        issues[0].to_string(),
+        "column products.sku belongs to table items"
+    );
+
+    table.remove_column("id");
+    table.set_index(Index::new(
+        "PRIMARY",
+        Arc::new(Column::new(
+            "products",
+            "id",
+            SqlDatatype::Int(10, SqlSigned::Unsigned),
+        )),
+        true,
+        true,
+    ));
+    assert!(table
+        .validate()
+        .unwrap_err()
+        .contains(&IntegrityIssue::MissingPrimaryKeyColumn {
+            table: Arc::new("products".to_string()),
+            column: Arc::new("id".to_string()),
+        }));
+}
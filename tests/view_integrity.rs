@@ -0,0 +1,42 @@
+use database_reflection::reflection::{Column, Database, SqlDatatype, SqlSigned, View};
+
+#[test]
+fn test_view_integrity() {
+    let mut view = View::new(
+        "verified_clients",
+        "select `clients`.`client_id` AS `client_id`, `clients`.`email` AS `email` from `clients` where `clients`.`is_email_verified` = 1",
+    );
+    view.set_column(Column::new(
+        "verified_clients",
+        "client_id",
+        SqlDatatype::Int(10, SqlSigned::Unsigned),
+    ))
+    .set_column(Column::new(
+        "verified_clients",
+        "email",
+        SqlDatatype::Varchar(255),
+    ))
+    .set_updatable(true)
+    .set_check_option(Some("CASCADED"))
+    .set_security_type(Some("DEFINER"))
+    .set_definer(Some("root@localhost"));
+
+    assert_eq!(view.name(), String::from("verified_clients").into());
+    assert!(view.definition().starts_with("select"));
+    assert_eq!(view.columns().len(), 2);
+    assert_eq!(
+        view.column("email").unwrap().datatype(),
+        &SqlDatatype::Varchar(255)
+    );
+    assert!(view.updatable());
+    assert_eq!(view.check_option(), Some("CASCADED"));
+    assert_eq!(view.security_type(), Some("DEFINER"));
+    assert_eq!(view.definer(), Some("root@localhost"));
+
+    let mut db = Database::new("test");
+    db.set_view(view);
+
+    assert!(db.table("verified_clients").is_none());
+    assert_eq!(db.views().len(), 1);
+    assert!(db.view("verified_clients").is_some());
+}
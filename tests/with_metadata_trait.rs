@@ -1,4 +1,6 @@
 use database_reflection::metadata::WithMetadata;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::HashMap;
 
 struct TestMetadata {
@@ -36,3 +38,95 @@ fn test_consts_are_public() {
     );
     assert!(test_metadata.meta_flag("test_flag"));
 }
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct WidgetHint {
+    widget: String,
+    rows: u32,
+}
+
+struct TestStructuredMetadata {
+    metadata: HashMap<String, String>,
+    metadata_values: HashMap<String, Value>,
+}
+
+impl TestStructuredMetadata {
+    pub fn new() -> TestStructuredMetadata {
+        TestStructuredMetadata {
+            metadata: HashMap::new(),
+            metadata_values: HashMap::new(),
+        }
+    }
+}
+
+impl WithMetadata for TestStructuredMetadata {
+    fn get_metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    fn get_metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.metadata
+    }
+
+    fn get_metadata_values(&self) -> Option<&HashMap<String, Value>> {
+        Some(&self.metadata_values)
+    }
+
+    fn get_metadata_values_mut(&mut self) -> Option<&mut HashMap<String, Value>> {
+        Some(&mut self.metadata_values)
+    }
+}
+
+#[test]
+fn test_structured_metadata() {
+    let mut test_metadata = TestStructuredMetadata::new();
+
+    test_metadata.set_meta_flag("test_flag");
+    assert_eq!(test_metadata.meta_value("test_flag"), Some(json!("1")));
+
+    test_metadata.set_meta_value("pii", json!(["email", "phone"]));
+    assert_eq!(
+        test_metadata.meta_as::<Vec<String>>("pii").unwrap(),
+        Some(vec![String::from("email"), String::from("phone")])
+    );
+    assert_eq!(
+        test_metadata.meta("pii"),
+        Some(String::from(r#"["email","phone"]"#))
+    );
+    assert!(test_metadata.meta_as::<u32>("pii").is_err());
+    assert_eq!(test_metadata.meta_as::<u32>("missing").unwrap(), None);
+    assert!(!test_metadata.get_metadata().contains_key("pii"));
+
+    let hint = WidgetHint {
+        widget: String::from("textarea"),
+        rows: 4,
+    };
+    test_metadata.set_meta_as("ui", &hint).unwrap();
+    assert_eq!(
+        test_metadata.meta_as::<WidgetHint>("ui").unwrap(),
+        Some(hint)
+    );
+    assert_eq!(test_metadata.meta_value("ui").unwrap()["rows"], json!(4));
+
+    test_metadata.set_meta("ui", "plain");
+    assert_eq!(test_metadata.meta("ui"), Some(String::from("plain")));
+    assert!(!test_metadata
+        .get_metadata_values()
+        .unwrap()
+        .contains_key("ui"));
+}
+
+#[test]
+fn test_structured_metadata_as_text() {
+    let mut test_metadata = TestMetadata::new();
+
+    test_metadata.set_meta_value("pii", json!(["email", "phone"]));
+    assert_eq!(
+        test_metadata.get_metadata().get("pii"),
+        Some(&String::from(r#"["email","phone"]"#))
+    );
+    assert_eq!(
+        test_metadata.meta_as::<Vec<String>>("pii").unwrap(),
+        Some(vec![String::from("email"), String::from("phone")])
+    );
+}